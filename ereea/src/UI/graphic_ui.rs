@@ -1,27 +1,32 @@
 use iced::widget::{Column, Container, Row, Space, Text, Toggler};
 use iced::{executor, time, Application, Command, Element, Font, Length, Subscription, Theme};
 
+use crate::maps::tile::ResourceType;
 use crate::robots::robot::RobotType;
 use crate::simulation::simulation::Simulation;
 
-use super::map_grid::MapGrid;
+use super::map_grid::{MapGrid, ViewMode};
 use super::utils::create_button;
 
 pub struct MapWindow {
     simulation: Simulation,
     map_grid: MapGrid,
-    auto_explore: bool,
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
     Tick,
     SendExplorer,
+    SendRelay,
     Pause,
     Play,
     UpSpeed,
     DownSpeed,
     ToggleAutoExplore(bool),
+    ToggleStationView(bool),
+    ToggleHeightMap(bool),
+    ToggleBiomes(bool),
+    SaveSnapshot,
 }
 
 impl Application for MapWindow {
@@ -45,7 +50,6 @@ impl Application for MapWindow {
             MapWindow {
                 simulation,
                 map_grid,
-                auto_explore: false,
             },
             Command::none(),
         )
@@ -59,19 +63,39 @@ impl Application for MapWindow {
         match message {
             Message::Tick => {
                 self.simulation.compute_fps();
+                self.simulation.update();
                 if let Ok(mut map) = self.simulation.map.write() {
                     self.map_grid.update(&mut map);
                 }
-                if self.auto_explore {
-                    self.simulation.send_robot(RobotType::Explorer, |_| {});
+            }
+            Message::SendExplorer => {
+                if let Err(error) = self.simulation.send_robot(RobotType::Explorer, |_| {}) {
+                    eprintln!("{}", error);
+                }
+            }
+            Message::SendRelay => {
+                let post = self.simulation.relay_post();
+                if let Err(error) = self.simulation.send_relay(post) {
+                    eprintln!("{}", error);
                 }
             }
-            Message::SendExplorer => self.simulation.send_robot(RobotType::Explorer, |_| {}),
             Message::Pause => self.simulation.pause(),
             Message::Play => self.simulation.play(),
             Message::UpSpeed => self.simulation.increase_speed(),
             Message::DownSpeed => self.simulation.decrease_speed(),
-            Message::ToggleAutoExplore(val) => self.auto_explore = val,
+            Message::ToggleAutoExplore(val) => self.simulation.auto_explore = val,
+            Message::ToggleStationView(val) => {
+                self.map_grid.mode = if val { ViewMode::Station } else { ViewMode::Omniscient };
+            }
+            Message::ToggleHeightMap(val) => self.map_grid.height_map = val,
+            Message::ToggleBiomes(val) => self.map_grid.biomes = val,
+            Message::SaveSnapshot => {
+                let path = format!("snapshot-tick-{}.json", self.simulation.tick);
+                match self.simulation.save(&path) {
+                    Ok(()) => println!("Instantané enregistré : {}", path),
+                    Err(error) => eprintln!("{}", error),
+                }
+            }
         }
         Command::none()
     }
@@ -80,23 +104,51 @@ impl Application for MapWindow {
         time::every(std::time::Duration::from_millis(33)).map(|_| Message::Tick)
     }
 
-    fn view(&self) -> Element<Message> {
-        let is_running = self
-            .simulation
-            .running
-            .load(std::sync::atomic::Ordering::SeqCst);
+    fn view(&self) -> Element<'_, Message> {
+        let is_running = self.simulation.running;
 
-        let located_resources_count = {
-            let located_resources = self.simulation.located_resources.lock().unwrap();
-            located_resources.len()
-        };
-
-        let energy_count = self.simulation.energy_count.lock().unwrap();
-
-        let stats = format!(
-            "FPS: {}\nResources found: {}\nEnergy: {}",
-            self.simulation.fps, located_resources_count, energy_count
+        let mut stats = format!(
+            "FPS: {}\nTick: {}\nResources found: {}\nCoverage: {:.1}%\nRobots lost: {}",
+            self.simulation.fps,
+            self.simulation.tick,
+            self.simulation.located_resources.iter().map(Vec::len).sum::<usize>(),
+            self.simulation.coverage(),
+            self.simulation.robots_lost
         );
+        let inventory = &self.simulation.inventory;
+        for (resource_type, total) in inventory.totals() {
+            stats.push_str(&format!("\n{}: {}", resource_type.name(), total));
+        }
+        stats.push_str(&format!(
+            "\nStock: {} energy, {} minerals",
+            inventory.stock(ResourceType::Energy),
+            inventory.stock(ResourceType::Mineral)
+        ));
+        if let Some(last) = inventory.history().last() {
+            stats.push_str(&format!(
+                "\nLast deposit: +{} {} (tick {})",
+                last.amount,
+                last.resource_type.name(),
+                last.tick
+            ));
+        }
+
+        let mut costs = String::from("Costs (energy/minerals)");
+        for robot_type in [
+            RobotType::Explorer,
+            RobotType::Harvester,
+            RobotType::Scientist,
+            RobotType::Relay,
+        ] {
+            let cost = self.simulation.robot_costs.cost(robot_type);
+            costs.push_str(&format!("\n{:?}: {}/{}", robot_type, cost.energy, cost.minerals));
+        }
+        let explorer_cost = self.simulation.robot_costs.cost(RobotType::Explorer);
+        let can_send_explorer = is_running && self.simulation.can_afford(RobotType::Explorer);
+        let relay_cost = self.simulation.robot_costs.cost(RobotType::Relay);
+        let can_send_relay = is_running
+            && self.simulation.can_afford(RobotType::Relay)
+            && self.simulation.relay_post().is_some();
 
         let status_icon = if is_running { "Running" } else { "Paused" };
 
@@ -107,11 +159,24 @@ impl Application for MapWindow {
             .push(Text::new(status_icon).size(16))
             .push(Space::with_height(10))
             .push(Text::new(stats))
+            .push(Text::new(costs))
             .push(Space::with_height(20))
             .push(create_button("Play", Message::Play, !is_running))
             .push(create_button("Pause", Message::Pause, is_running))
             .push(Space::with_height(10))
-            .push(create_button("Send Explorer", Message::SendExplorer, is_running))
+            .push(create_button(
+                format!(
+                    "Send Explorer ({}/{})",
+                    explorer_cost.energy, explorer_cost.minerals
+                ),
+                Message::SendExplorer,
+                can_send_explorer,
+            ))
+            .push(create_button(
+                format!("Send Relay ({}/{})", relay_cost.energy, relay_cost.minerals),
+                Message::SendRelay,
+                can_send_relay,
+            ))
             .push(
                 Row::new()
                     .spacing(10)
@@ -119,11 +184,32 @@ impl Application for MapWindow {
                     .push(create_button("- Speed", Message::DownSpeed, true)),
             )
             .push(
-                    Toggler::new(Some("Auto-Explore".into()), self.auto_explore, Message::ToggleAutoExplore)
+                    Toggler::new(Some("Auto-Explore".into()), self.simulation.auto_explore, Message::ToggleAutoExplore)
+                    .spacing(10),
+            )
+            .push(
+                Toggler::new(
+                    Some("Station view".into()),
+                    self.map_grid.mode == ViewMode::Station,
+                    Message::ToggleStationView,
+                )
+                .spacing(10),
+            )
+            .push(
+                Toggler::new(
+                    Some("Height map".into()),
+                    self.map_grid.height_map,
+                    Message::ToggleHeightMap,
+                )
+                .spacing(10),
+            )
+            .push(
+                Toggler::new(Some("Biomes".into()), self.map_grid.biomes, Message::ToggleBiomes)
                     .spacing(10),
-            );
+            )
+            .push(create_button("Save Snapshot", Message::SaveSnapshot, true));
 
-        let map = self.map_grid.view().map(|_| Message::Tick);
+        let map = self.map_grid.view(&self.simulation).map(|_| Message::Tick);
 
         Row::new()
             .push(Container::new(controls).width(Length::FillPortion(3)))
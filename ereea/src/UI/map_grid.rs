@@ -1,26 +1,56 @@
+use crate::maps::biome::Biome;
+use crate::maps::knowledge::Visibility;
 use crate::maps::map::Map;
-use iced::widget::{Column, Container, Row, Text};
-use iced::{Element, Font, Length};
+use crate::simulation::simulation::Simulation;
+use iced::widget::{container, Column, Container, Row, Text};
+use iced::{Background, Color, Element, Font, Length, Theme};
 use std::sync::{Arc, RwLock};
 
 use super::graphic_ui::Message;
 
+/// Carte affichée : la vérité du terrain, ou seulement ce que sait la station.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewMode {
+    #[default]
+    Omniscient,
+    Station,
+}
+
+/// Fond d'une case : brouillard de la vue station, teinte du relief ou du biome.
+struct Shade(Color);
+
+impl container::StyleSheet for Shade {
+    type Style = Theme;
+
+    fn appearance(&self, _: &Theme) -> container::Appearance {
+        container::Appearance {
+            background: Some(Background::Color(self.0)),
+            ..Default::default()
+        }
+    }
+}
+
 pub struct MapGrid {
     map: Arc<RwLock<Map>>,
     pub font: Font,
+    pub mode: ViewMode,
+    /// Teinte le fond des cases visibles selon leur altitude.
+    pub height_map: bool,
+    /// Teinte le fond des cases visibles selon leur biome.
+    pub biomes: bool,
     map_content: String,
 }
 
 impl MapGrid {
     pub fn new(map: Arc<RwLock<Map>>, font: Font) -> Self {
-        Self { map, font, map_content: String::new() }
+        Self { map, font, mode: ViewMode::default(), height_map: false, biomes: true, map_content: String::new() }
     }
 
     pub fn update(&mut self, map: &mut Map) {
         let mut map_content = String::new();
         for y in 0..map.height {
             for x in 0..map.width {
-                let tile = map.get(x, y).tile;
+                let tile = map.visible_tile(x, y);
                 map_content.push(tile.char());
             }
             map_content.push('\n');
@@ -28,7 +58,39 @@ impl MapGrid {
         self.map_content = map_content;
     }
 
-    pub fn view(&self) -> Element<Message> {
+    /// Case de la vue station : tuile connue et fond selon sa fraîcheur.
+    fn station_cell(&self, simulation: &Simulation, x: usize, y: usize) -> (String, Option<Color>) {
+        match simulation.visibility(x, y) {
+            Visibility::Unknown => (String::new(), Some(Color::from_rgb8(30, 30, 30))),
+            Visibility::Stale(known) => {
+                (known.tile.char().to_string(), Some(Color::from_rgb8(150, 150, 150)))
+            }
+            Visibility::Fresh(known) => (known.tile.char().to_string(), None),
+        }
+    }
+
+    /// Teinte d'altitude : des creux sombres et bleutés aux sommets clairs.
+    fn height_shade(elevation: f64) -> Color {
+        let height = ((elevation + 1.0) / 2.0).clamp(0.0, 1.0) as f32;
+        let low = (0.15, 0.25, 0.40);
+        let high = (0.95, 0.90, 0.80);
+        Color::from_rgb(
+            low.0 + (high.0 - low.0) * height,
+            low.1 + (high.1 - low.1) * height,
+            low.2 + (high.2 - low.2) * height,
+        )
+    }
+
+    fn biome_shade(biome: Biome) -> Color {
+        match biome {
+            Biome::Plains => Color::from_rgb8(214, 226, 190),
+            Biome::Crater => Color::from_rgb8(196, 160, 120),
+            Biome::Ice => Color::from_rgb8(210, 232, 245),
+            Biome::RockField => Color::from_rgb8(170, 170, 165),
+        }
+    }
+
+    pub fn view(&self, simulation: &Simulation) -> Element<'_, Message> {
         if let Ok(map) = self.map.read() {
             let mut rows = Column::new().spacing(1);
 
@@ -36,12 +98,24 @@ impl MapGrid {
                 let mut row = Row::new().spacing(1);
 
                 for x in 0..map.width {
-                    let emoji = map.get(x, y).tile.char().to_string();
-                    let cell = Container::new(Text::new(emoji).font(self.font))
+                    let (emoji, fog) = match self.mode {
+                        ViewMode::Omniscient => (map.visible_tile(x, y).char().to_string(), None),
+                        ViewMode::Station => self.station_cell(simulation, x, y),
+                    };
+                    let mut cell = Container::new(Text::new(emoji).font(self.font))
                         .width(Length::Fixed(30.0))
                         .height(Length::Fixed(30.0))
                         .center_x()
                         .center_y();
+                    let shade = fog
+                        .or_else(|| {
+                            self.height_map
+                                .then(|| Self::height_shade(map.elevation(x, y)))
+                        })
+                        .or_else(|| self.biomes.then(|| Self::biome_shade(map.biome(x, y))));
+                    if let Some(color) = shade {
+                        cell = cell.style(iced::theme::Container::Custom(Box::new(Shade(color))));
+                    }
 
                     row = row.push(cell);
                 }
@@ -50,7 +124,7 @@ impl MapGrid {
             }
 
             Container::new(rows)
-                .width(Length::Fill) 
+                .width(Length::Fill)
                 .height(Length::Fill)
                 .center_x()
                 .center_y()
@@ -64,4 +138,4 @@ impl MapGrid {
                 .into()
         }
     }
-}
\ No newline at end of file
+}
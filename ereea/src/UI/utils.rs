@@ -2,7 +2,11 @@ use iced::{widget::{Button, Text}, Application, Length, Settings};
 use crate::simulation::simulation::Simulation;
 use super::graphic_ui::{MapWindow, Message};
 
-pub fn create_button(label: &str, message: Message, enabled: bool ) -> Button<Message> {
+pub fn create_button<'a>(
+    label: impl Into<std::borrow::Cow<'a, str>>,
+    message: Message,
+    enabled: bool,
+) -> Button<'a, Message> {
     let mut button = Button::new(
         Text::new(label)
             .horizontal_alignment(iced::alignment::Horizontal::Center)
@@ -24,7 +28,7 @@ pub fn create_button(label: &str, message: Message, enabled: bool ) -> Button<Me
     button
 }
 
-pub fn open_window(simulation: &Simulation) -> iced::Result {
+pub fn open_window(simulation: Simulation) -> iced::Result {
     let tile_size = 30;
     let control_width = 200;
     let padding = 10;
@@ -46,7 +50,7 @@ pub fn open_window(simulation: &Simulation) -> iced::Result {
         },
         antialiasing: true,
         exit_on_close_request: true,
-        ..Settings::with_flags(simulation.clone())
+        ..Settings::with_flags(simulation)
     };
 
     MapWindow::run(settings)?;
@@ -1,4 +1,6 @@
 pub mod maps;
+pub mod pathfinding;
 pub mod robots;
 pub mod simulation;
+#[allow(non_snake_case)]
 pub mod UI;
\ No newline at end of file
@@ -1,11 +1,70 @@
-mod simulation;
-mod maps;
-mod robots;
-mod UI;
-
-use simulation::simulation::Simulation;
+use ereea::simulation::events::EventLog;
+use ereea::simulation::headless::{run_headless, HeadlessOptions};
+use ereea::simulation::replay::Replay;
+use ereea::simulation::scenario::Scenario;
+use ereea::simulation::simulation::Simulation;
 
 fn main() {
-    let mut simulation = Simulation::new(4);
-    simulation.run();
-}
\ No newline at end of file
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "--headless") {
+        match HeadlessOptions::from_args(args).and_then(|options| {
+            run_headless(&options).map(|summary| summary.render(options.format))
+        }) {
+            Ok(summary) => println!("{}", summary),
+            Err(error) => {
+                eprintln!("{}", error);
+                std::process::exit(2);
+            }
+        }
+        return;
+    }
+
+    if let Some(path) = option_value(&args, "--replay") {
+        let result = EventLog::load(path).and_then(|log| {
+            let replay = Replay::new(log);
+            let tick = match option_value(&args, "--at-tick") {
+                Some(tick) => tick
+                    .parse()
+                    .map_err(|_| format!("invalid value '{}' for --at-tick", tick))?,
+                None => replay.last_tick(),
+            };
+            Ok(replay.state_at(tick))
+        });
+        match result {
+            Ok(state) => {
+                print!("{}", state.render());
+                println!("tick: {}\nrobots: {}", state.tick, state.robots.len());
+                for (resource_type, total) in state.inventory.totals() {
+                    println!("{} credited: {}", resource_type.name(), total);
+                }
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                std::process::exit(2);
+            }
+        }
+        return;
+    }
+
+    let simulation = if let Some(path) = option_value(&args, "--load") {
+        Simulation::load(path)
+    } else if let Some(path) = option_value(&args, "--scenario") {
+        Scenario::load(path).map(|scenario| Simulation::from_scenario(&scenario))
+    } else {
+        Ok(Simulation::new(4))
+    };
+
+    match simulation {
+        Ok(simulation) => simulation.run(),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        }
+    }
+}
+
+fn option_value<'a>(args: &'a [String], name: &str) -> Option<&'a String> {
+    let index = args.iter().position(|arg| arg == name)?;
+    args.get(index + 1)
+}
@@ -0,0 +1,151 @@
+use noise::{Fbm, MultiFractal, Perlin, RidgedMulti};
+use serde::{Deserialize, Serialize};
+
+pub const BIOME_SCALE: f64 = 12.0;
+pub const BIOME_OCTAVES: usize = 4;
+pub const HAZARD_DRAIN: u32 = 10;
+/// Au-delà, les crêtes du bruit ridged forment des cratères.
+pub const CRATER_THRESHOLD: f64 = 0.45;
+/// En deçà, le bruit fBm donne de la glace ; au-delà de l'opposé, des champs de roches.
+pub const ICE_THRESHOLD: f64 = -0.2;
+pub const ROCK_THRESHOLD: f64 = 0.2;
+
+/// Région de la carte, tirée d'un bruit à plusieurs octaves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Biome {
+    #[default]
+    Plains,
+    Crater,
+    Ice,
+    RockField,
+}
+
+impl Biome {
+    pub const ALL: [Biome; 4] = [Biome::Plains, Biome::Crater, Biome::Ice, Biome::RockField];
+}
+
+/// Effets d'un biome sur les cases qu'il couvre.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BiomeProfile {
+    /// Multiplie la probabilité de chaque ressource.
+    pub resource_density: f64,
+    /// Probabilité, à chaque tick, qu'un robot sur la case subisse un incident.
+    pub hazard_rate: f64,
+    /// Multiplie le coût d'entrée de la case ; au-delà de 1, chaque unité entière
+    /// ajoute aussi un tick au déplacement.
+    pub movement_cost: f64,
+}
+
+impl Default for BiomeProfile {
+    fn default() -> Self {
+        BiomeProfile::new(1.0, 0.0, 1.0)
+    }
+}
+
+impl BiomeProfile {
+    pub fn new(resource_density: f64, hazard_rate: f64, movement_cost: f64) -> Self {
+        BiomeProfile {
+            resource_density,
+            hazard_rate,
+            movement_cost,
+        }
+    }
+}
+
+/// Génération des biomes et profil de chacun.
+///
+/// Un bruit ridged trace les cratères sur ses crêtes ; ailleurs, un bruit fBm
+/// sépare la glace, les plaines et les champs de roches.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BiomeConfig {
+    pub scale: f64,
+    pub octaves: usize,
+    /// Charge perdue par un robot lors d'un incident.
+    pub hazard_drain: u32,
+    pub plains: BiomeProfile,
+    pub crater: BiomeProfile,
+    pub ice: BiomeProfile,
+    pub rock_field: BiomeProfile,
+}
+
+impl Default for BiomeConfig {
+    fn default() -> Self {
+        BiomeConfig {
+            scale: BIOME_SCALE,
+            octaves: BIOME_OCTAVES,
+            hazard_drain: HAZARD_DRAIN,
+            plains: BiomeProfile::default(),
+            crater: BiomeProfile::new(2.0, 0.02, 1.5),
+            ice: BiomeProfile::new(0.5, 0.05, 1.0),
+            rock_field: BiomeProfile::new(1.5, 0.01, 2.0),
+        }
+    }
+}
+
+impl BiomeConfig {
+    pub fn profile(&self, biome: Biome) -> BiomeProfile {
+        match biome {
+            Biome::Plains => self.plains,
+            Biome::Crater => self.crater,
+            Biome::Ice => self.ice,
+            Biome::RockField => self.rock_field,
+        }
+    }
+
+    /// Bruits de génération, dérivés de la graine de la carte.
+    pub fn noises(&self, seed: u32) -> BiomeNoise {
+        BiomeNoise {
+            fbm: Fbm::new(seed.wrapping_add(2)).set_octaves(self.octaves),
+            ridged: RidgedMulti::new(seed.wrapping_add(3)).set_octaves(self.octaves),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.scale <= 0.0 {
+            return Err(String::from("biome scale must be positive"));
+        }
+        if !(1..=Fbm::<Perlin>::MAX_OCTAVES).contains(&self.octaves) {
+            return Err(format!(
+                "biome octaves must be between 1 and {}",
+                Fbm::<Perlin>::MAX_OCTAVES
+            ));
+        }
+        for biome in Biome::ALL {
+            let profile = self.profile(biome);
+            if profile.resource_density < 0.0 || profile.movement_cost <= 0.0 {
+                return Err(format!(
+                    "{:?} needs a non-negative resource density and a positive movement cost",
+                    biome
+                ));
+            }
+            if !(0.0..=1.0).contains(&profile.hazard_rate) {
+                return Err(format!("hazard rate of {:?} must be between 0 and 1", biome));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Les deux bruits à plusieurs octaves qui décident du biome d'une case.
+pub struct BiomeNoise {
+    pub fbm: Fbm<Perlin>,
+    pub ridged: RidgedMulti<Perlin>,
+}
+
+impl BiomeNoise {
+    /// Biome correspondant aux valeurs des deux bruits pour une case.
+    pub fn classify(ridged: f64, fbm: f64) -> Biome {
+        if ridged > CRATER_THRESHOLD {
+            Biome::Crater
+        } else if fbm < ICE_THRESHOLD {
+            Biome::Ice
+        } else if fbm > ROCK_THRESHOLD {
+            Biome::RockField
+        } else {
+            Biome::Plains
+        }
+    }
+}
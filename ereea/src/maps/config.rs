@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+
+use super::biome::BiomeConfig;
+use super::movement::Movement;
+use super::tile::{ResourceType, TileType};
+use super::topology::Topology;
+
+pub const TERRAIN_SCALE: f64 = 6.0;
+pub const RESOURCE_SCALE: f64 = 2.0;
+pub const RESOURCE_PROBABILITY: f64 = 0.1;
+pub const THRESHOLD: f64 = 0.3;
+pub const RESOURCE_AMOUNT: u32 = 10;
+pub const SCIENCE_PROBABILITY: f64 = 0.03;
+pub const SCIENCE_POINTS: u32 = 5;
+pub const SLOPE_COST: f64 = 4.0;
+pub const CLIFF_HEIGHT: f64 = 0.45;
+pub const VISION_PER_HEIGHT: f64 = 5.0;
+
+/// Répartition d'un type de ressource sur la carte.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ResourceDistribution {
+    pub resource_type: ResourceType,
+    /// Probabilité qu'une case éligible reçoive cette ressource.
+    pub probability: f64,
+    /// Quantité déposée sur chaque case.
+    pub amount: u32,
+}
+
+impl ResourceDistribution {
+    pub fn new(resource_type: ResourceType, probability: f64, amount: u32) -> Self {
+        ResourceDistribution {
+            resource_type,
+            probability,
+            amount,
+        }
+    }
+}
+
+/// Coût d'entrée sur chaque type de case ; `None` rend la case infranchissable.
+/// `robot` remplace le coût d'une case occupée par un autre robot : le robot attend qu'elle se libère.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MovementCosts {
+    pub empty: Option<f64>,
+    pub terrain: Option<f64>,
+    pub base: Option<f64>,
+    pub resource: Option<f64>,
+    pub robot: Option<f64>,
+}
+
+impl Default for MovementCosts {
+    fn default() -> Self {
+        MovementCosts {
+            empty: Some(1.0),
+            terrain: None,
+            base: Some(1.0),
+            resource: None,
+            robot: Some(4.0),
+        }
+    }
+}
+
+impl MovementCosts {
+    pub fn cost(&self, tile: TileType) -> Option<f64> {
+        match tile {
+            TileType::Empty => self.empty,
+            TileType::Terrain => self.terrain,
+            TileType::Base => self.base,
+            TileType::Resource(_) => self.resource,
+            TileType::Robot(_) => self.robot,
+        }
+    }
+}
+
+/// Effets du relief : la valeur continue du bruit de terrain sert d'altitude.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ElevationConfig {
+    /// Ticks d'escalade par unité d'altitude gravie ; chacun consomme un déplacement de batterie.
+    pub slope_cost: f64,
+    /// Dénivelé au-delà duquel on ne passe pas d'une case à sa voisine, dans un sens comme dans l'autre.
+    pub cliff_height: Option<f64>,
+    /// Portée de vue, en pas, au niveau zéro.
+    pub vision_range: usize,
+    /// Pas de vue gagnés par unité d'altitude au-dessus de zéro.
+    pub vision_per_height: f64,
+}
+
+impl Default for ElevationConfig {
+    fn default() -> Self {
+        ElevationConfig {
+            slope_cost: SLOPE_COST,
+            cliff_height: Some(CLIFF_HEIGHT),
+            vision_range: 1,
+            vision_per_height: VISION_PER_HEIGHT,
+        }
+    }
+}
+
+/// Paramètres de génération d'une carte.
+///
+/// Une case vide dont le bruit de ressource dépasse `threshold` tire les
+/// distributions de `resources` dans l'ordre ; la première qui réussit l'emporte.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapConfig {
+    pub width: usize,
+    pub height: usize,
+    pub terrain_scale: f64,
+    pub resource_scale: f64,
+    pub threshold: f64,
+    pub resources: Vec<ResourceDistribution>,
+    pub movement_costs: MovementCosts,
+    pub movement: Movement,
+    pub topology: Topology,
+    pub elevation: ElevationConfig,
+    pub biomes: BiomeConfig,
+}
+
+impl Default for MapConfig {
+    fn default() -> Self {
+        MapConfig::new(25, 25)
+    }
+}
+
+impl MapConfig {
+    pub fn new(width: usize, height: usize) -> Self {
+        MapConfig {
+            width,
+            height,
+            terrain_scale: TERRAIN_SCALE,
+            resource_scale: RESOURCE_SCALE,
+            threshold: THRESHOLD,
+            resources: vec![
+                ResourceDistribution::new(
+                    ResourceType::Energy,
+                    RESOURCE_PROBABILITY,
+                    RESOURCE_AMOUNT,
+                ),
+                ResourceDistribution::new(
+                    ResourceType::Mineral,
+                    RESOURCE_PROBABILITY,
+                    RESOURCE_AMOUNT,
+                ),
+                ResourceDistribution::new(
+                    ResourceType::Science,
+                    SCIENCE_PROBABILITY,
+                    SCIENCE_POINTS,
+                ),
+            ],
+            movement_costs: MovementCosts::default(),
+            movement: Movement::default(),
+            topology: Topology::default(),
+            elevation: ElevationConfig::default(),
+            biomes: BiomeConfig::default(),
+        }
+    }
+
+    pub fn terrain_scale(mut self, terrain_scale: f64) -> Self {
+        self.terrain_scale = terrain_scale;
+        self
+    }
+
+    pub fn resource_scale(mut self, resource_scale: f64) -> Self {
+        self.resource_scale = resource_scale;
+        self
+    }
+
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Applique la même probabilité à tous les types de ressource.
+    pub fn resource_probability(mut self, probability: f64) -> Self {
+        for distribution in self.resources.iter_mut() {
+            distribution.probability = probability;
+        }
+        self
+    }
+
+    /// Applique la même quantité à tous les types de ressource.
+    pub fn resource_amount(mut self, amount: u32) -> Self {
+        for distribution in self.resources.iter_mut() {
+            distribution.amount = amount;
+        }
+        self
+    }
+
+    /// Remplace la répartition d'un type de ressource, ou l'ajoute en fin de liste.
+    pub fn resource(mut self, distribution: ResourceDistribution) -> Self {
+        match self
+            .resources
+            .iter_mut()
+            .find(|existing| existing.resource_type == distribution.resource_type)
+        {
+            Some(existing) => *existing = distribution,
+            None => self.resources.push(distribution),
+        }
+        self
+    }
+
+    pub fn movement_costs(mut self, movement_costs: MovementCosts) -> Self {
+        self.movement_costs = movement_costs;
+        self
+    }
+
+    pub fn movement(mut self, movement: Movement) -> Self {
+        self.movement = movement;
+        self
+    }
+
+    pub fn topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn elevation(mut self, elevation: ElevationConfig) -> Self {
+        self.elevation = elevation;
+        self
+    }
+
+    pub fn biomes(mut self, biomes: BiomeConfig) -> Self {
+        self.biomes = biomes;
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.width < 3 || self.height < 3 {
+            return Err(String::from("map must be at least 3x3"));
+        }
+        if self.topology == Topology::Sphere && !self.width.is_multiple_of(2) {
+            return Err(String::from("a spherical map needs an even width"));
+        }
+        if self.terrain_scale <= 0.0 || self.resource_scale <= 0.0 {
+            return Err(String::from("noise scales must be positive"));
+        }
+        let costs = self.movement_costs;
+        if [costs.empty, costs.terrain, costs.base, costs.resource, costs.robot]
+            .into_iter()
+            .flatten()
+            .any(|cost| cost <= 0.0)
+        {
+            return Err(String::from("movement costs must be positive"));
+        }
+        let elevation = self.elevation;
+        if elevation.slope_cost < 0.0 || elevation.vision_per_height < 0.0 {
+            return Err(String::from(
+                "slope cost and vision per height must not be negative",
+            ));
+        }
+        if elevation.cliff_height.is_some_and(|height| height <= 0.0) {
+            return Err(String::from("cliff height must be positive"));
+        }
+        self.biomes.validate()?;
+        if let Some(distribution) = self
+            .resources
+            .iter()
+            .find(|distribution| !(0.0..=1.0).contains(&distribution.probability))
+        {
+            return Err(format!(
+                "probability of {:?} must be between 0 and 1",
+                distribution.resource_type
+            ));
+        }
+        Ok(())
+    }
+}
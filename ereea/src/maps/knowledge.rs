@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+use super::tile::{Resource, TileType};
+
+/// Ce qu'un robot (ou la station) sait d'une case, et quand il l'a appris.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KnownTile {
+    pub tile: TileType,
+    /// Un robot peut s'y tenir.
+    pub standable: bool,
+    /// Tick de l'observation.
+    pub tick: u64,
+}
+
+/// Au-delà de ce nombre de ticks, une observation est considérée comme périmée.
+pub const DEFAULT_FRESHNESS_TICKS: u64 = 100;
+
+/// État d'une case dans une carte de connaissance, à un tick donné.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Visibility {
+    Unknown,
+    /// Connue, mais observée il y a plus de `freshness_ticks` ticks.
+    Stale(KnownTile),
+    Fresh(KnownTile),
+}
+
+/// Nombre de cases mises à jour de chaque côté lors d'une synchronisation à la base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MergeReport {
+    /// Cases que le robot a apprises de la station.
+    pub pulled: usize,
+    /// Cases que la station a apprises du robot.
+    pub pushed: usize,
+}
+
+/// Carte partielle de ce qui a été observé, case par case.
+///
+/// Chaque robot en porte une ; la station garde la carte de référence.
+/// Deux cartes se fusionnent comme deux branches : pour chaque case,
+/// l'observation la plus récente l'emporte (une ressource vidée depuis sa découverte
+/// redevient vide), et à tick égal la version déjà connue est gardée.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KnowledgeMap {
+    pub width: usize,
+    pub height: usize,
+    tiles: Vec<Option<KnownTile>>,
+}
+
+impl Default for KnowledgeMap {
+    fn default() -> Self {
+        KnowledgeMap::new(0, 0)
+    }
+}
+
+impl KnowledgeMap {
+    pub fn new(width: usize, height: usize) -> Self {
+        KnowledgeMap {
+            width,
+            height,
+            tiles: vec![None; width * height],
+        }
+    }
+
+    fn get_index(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<KnownTile> {
+        self.tiles[self.get_index(x, y)]
+    }
+
+    /// Enregistre une observation ; ignorée si la case est déjà connue plus récemment.
+    /// Retourne `true` si la case a changé.
+    pub fn observe(&mut self, x: usize, y: usize, observation: KnownTile) -> bool {
+        let idx = self.get_index(x, y);
+        match self.tiles[idx] {
+            Some(known) if known.tick >= observation.tick => false,
+            _ => {
+                self.tiles[idx] = Some(observation);
+                true
+            }
+        }
+    }
+
+    /// Récupère les observations plus récentes de `other`.
+    /// Retourne le nombre de cases mises à jour.
+    pub fn merge(&mut self, other: &KnowledgeMap) -> usize {
+        let mut updated = 0;
+        for (idx, theirs) in other.tiles.iter().enumerate() {
+            let Some(theirs) = *theirs else {
+                continue;
+            };
+            let newer = match self.tiles[idx] {
+                Some(ours) => theirs.tick > ours.tick,
+                None => true,
+            };
+            if newer {
+                self.tiles[idx] = Some(theirs);
+                updated += 1;
+            }
+        }
+        updated
+    }
+
+    /// Pousse les découvertes de `self` vers `station`, puis tire ce qu'elle sait de plus.
+    /// Une carte encore vide (robot construit hors simulation) prend la taille de la station.
+    pub fn sync(&mut self, station: &mut KnowledgeMap) -> MergeReport {
+        if self.tiles.len() != station.tiles.len() {
+            *self = KnowledgeMap::new(station.width, station.height);
+        }
+        let pushed = station.merge(self);
+        let pulled = self.merge(station);
+        MergeReport { pulled, pushed }
+    }
+
+    /// Fraîcheur de la case au tick `now`.
+    pub fn visibility(&self, x: usize, y: usize, now: u64, freshness_ticks: u64) -> Visibility {
+        match self.get(x, y) {
+            None => Visibility::Unknown,
+            Some(known) if now.saturating_sub(known.tick) > freshness_ticks => {
+                Visibility::Stale(known)
+            }
+            Some(known) => Visibility::Fresh(known),
+        }
+    }
+
+    /// Pourcentage des cases de la carte qui sont connues.
+    pub fn coverage(&self) -> f64 {
+        if self.tiles.is_empty() {
+            return 0.0;
+        }
+        self.known_count() as f64 * 100.0 / self.tiles.len() as f64
+    }
+
+    /// Nombre de cases connues.
+    pub fn known_count(&self) -> usize {
+        self.tiles.iter().filter(|tile| tile.is_some()).count()
+    }
+
+    /// Cases connues avec leur dernière observation.
+    pub fn known(&self) -> impl Iterator<Item = ((usize, usize), KnownTile)> + '_ {
+        self.tiles.iter().enumerate().filter_map(|(idx, tile)| {
+            tile.map(|tile| ((idx % self.width, idx / self.width), tile))
+        })
+    }
+
+    /// Ressources connues, d'après la dernière observation de chaque case.
+    pub fn resources(&self) -> impl Iterator<Item = (usize, usize, Resource)> + '_ {
+        self.known().filter_map(|((x, y), known)| match known.tile {
+            TileType::Resource(resource) => Some((x, y, resource)),
+            _ => None,
+        })
+    }
+}
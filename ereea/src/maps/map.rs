@@ -1,33 +1,64 @@
-use noise::{NoiseFn, Perlin};
-use rand::Rng;
+use noise::Perlin;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
-use super::tile::{MapTile, Resource, ResourceType, TileType};
+use super::biome::{Biome, BiomeNoise, BiomeProfile};
+use super::config::MapConfig;
+use super::movement::{Movement, Neighbourhood};
+use super::tile::{MapTile, Resource, TileType};
+use super::topology::Topology;
+use crate::robots::robot::RobotType;
 
-pub const TERRAIN_SCALE: f64 = 6.0;
-pub const RESOURCE_SCALE: f64 = 2.0;
-const RESOURCE_PROBABILITY: f64 = 0.1;
-const THRESHOLD: f64 = 0.3;
-
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Map {
     pub width: usize,
     pub height: usize,
     pub grid: Vec<MapTile>,
+    /// Altitude de chaque case : la valeur du bruit de terrain, entre -1 et 1 environ.
+    #[serde(default)]
+    elevation: Vec<f64>,
+    /// Biome de chaque case.
+    #[serde(default)]
+    biomes: Vec<Biome>,
     pub seed: u32,
     pub base_position: (usize, usize),
+    /// Seule source d'aléatoire de la carte et des robots, dérivée de `seed`.
+    pub rng: ChaCha8Rng,
+    pub config: MapConfig,
+    /// Couche des robots, séparée du terrain de `grid` : (id, type) par case occupée.
+    /// Elle n'est pas sérialisée et se reconstruit à partir des robots.
+    #[serde(skip)]
+    robots: HashMap<(usize, usize), Vec<(usize, RobotType)>>,
+    /// Cases réclamées par un robot pendant le tick en cours.
+    #[serde(skip)]
+    reservations: HashSet<(usize, usize)>,
 }
 
 impl Map {
     pub fn new(width: usize, height: usize, seed: u32) -> Self {
+        Self::with_config(MapConfig::new(width, height), seed)
+    }
+
+    pub fn with_config(config: MapConfig, seed: u32) -> Self {
+        let (width, height) = (config.width, config.height);
         let mut map = Map {
             width,
             height,
             grid: vec![MapTile::new(0, 0, TileType::Empty); width * height],
+            elevation: vec![0.0; width * height],
+            biomes: vec![Biome::default(); width * height],
             seed,
             base_position: (0, 0),
+            rng: ChaCha8Rng::seed_from_u64(seed as u64),
+            config,
+            robots: HashMap::new(),
+            reservations: HashSet::new(),
         };
 
         map.generate_terrain();
+        map.generate_biomes();
         map.place_resources();
         map.place_science_base();
         map
@@ -46,22 +77,173 @@ impl Map {
         self.grid[idx] = tile;
     }
 
+    /// Altitude de la case ; 0 pour une carte enregistrée avant la couche d'altitude.
+    pub fn elevation(&self, x: usize, y: usize) -> f64 {
+        self.elevation
+            .get(self.get_index(x, y))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn set_elevation(&mut self, x: usize, y: usize, elevation: f64) {
+        if self.elevation.len() != self.grid.len() {
+            self.elevation = vec![0.0; self.grid.len()];
+        }
+        let idx = self.get_index(x, y);
+        self.elevation[idx] = elevation;
+    }
+
+    /// Biome de la case ; des plaines pour une carte enregistrée avant les biomes.
+    pub fn biome(&self, x: usize, y: usize) -> Biome {
+        self.biomes
+            .get(self.get_index(x, y))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn set_biome(&mut self, x: usize, y: usize, biome: Biome) {
+        if self.biomes.len() != self.grid.len() {
+            self.biomes = vec![Biome::default(); self.grid.len()];
+        }
+        let idx = self.get_index(x, y);
+        self.biomes[idx] = biome;
+    }
+
+    pub fn biome_profile(&self, x: usize, y: usize) -> BiomeProfile {
+        self.config.biomes.profile(self.biome(x, y))
+    }
+
+    /// Probabilité, à chaque tick, qu'un robot sur la case subisse un incident.
+    pub fn hazard_rate(&self, x: usize, y: usize) -> f64 {
+        self.biome_profile(x, y).hazard_rate
+    }
+
+    /// Altitude gravie en passant de `from` à `to` ; 0 en descente.
+    pub fn climb(&self, from: (usize, usize), to: (usize, usize)) -> f64 {
+        (self.elevation(to.0, to.1) - self.elevation(from.0, from.1)).max(0.0)
+    }
+
+    /// Le dénivelé entre deux cases voisines dépasse la hauteur d'une falaise.
+    pub fn is_cliff(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        let drop = (self.elevation(to.0, to.1) - self.elevation(from.0, from.1)).abs();
+        self.config
+            .elevation
+            .cliff_height
+            .is_some_and(|height| drop > height)
+    }
+
+    /// Ticks d'escalade nécessaires avant d'entrer sur `to` depuis `from`.
+    pub fn slope_penalty(&self, from: (usize, usize), to: (usize, usize)) -> u32 {
+        (self.climb(from, to) * self.config.elevation.slope_cost).round() as u32
+    }
+
+    /// Ticks d'attente avant d'entrer sur `to` depuis `from` : l'escalade,
+    /// plus un tick par unité entière de coût du biome au-delà de 1.
+    pub fn step_penalty(&self, from: (usize, usize), to: (usize, usize)) -> u32 {
+        let terrain = (self.biome_profile(to.0, to.1).movement_cost - 1.0).max(0.0) as u32;
+        self.slope_penalty(from, to) + terrain
+    }
+
+    /// Portée de vue depuis la case : plus loin sur les hauteurs.
+    pub fn vision_range(&self, (x, y): (usize, usize)) -> usize {
+        let elevation = &self.config.elevation;
+        let bonus = self.elevation(x, y).max(0.0) * elevation.vision_per_height;
+        elevation.vision_range + bonus as usize
+    }
+
+    /// Cases à portée de vue de `position`, en commençant par elle-même.
+    pub fn visible_from(&self, position: (usize, usize)) -> Vec<(usize, usize)> {
+        self.neighbourhood()
+            .within(position, self.vision_range(position))
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.config.topology
+    }
+
+    /// Bruit de la case selon la topologie, pour une génération sans couture aux bords.
+    fn noise(&self, perlin: &Perlin, position: (usize, usize), scale: f64) -> f64 {
+        self.topology()
+            .sample(perlin, position, scale, self.width, self.height)
+    }
+
+    pub fn movement(&self) -> Movement {
+        self.config.movement
+    }
+
+    pub fn neighbourhood(&self) -> Neighbourhood {
+        Neighbourhood {
+            topology: self.topology(),
+            movement: self.movement(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Voisines de la case avec le facteur de coût du pas ; les diagonales
+    /// ne coupent pas le coin d'une case de relief et les falaises ne se franchissent pas.
+    pub fn moves(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), f64)> + '_ {
+        self.neighbourhood()
+            .moves(position, |(x, y)| self.get(x, y).tile == TileType::Terrain)
+            .filter(move |&(next, _)| !self.is_cliff(position, next))
+    }
+
+    /// Voisines de la case selon la topologie et le modèle de déplacement.
+    pub fn neighbours(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.moves(position).map(|(next, _)| next)
+    }
+
+    pub fn is_adjacent(&self, a: (usize, usize), b: (usize, usize)) -> bool {
+        self.neighbours(a).any(|neighbour| neighbour == b)
+    }
+
+    /// Nombre minimal de pas entre deux cases, sans tenir compte des obstacles.
+    pub fn distance(&self, a: (usize, usize), b: (usize, usize)) -> usize {
+        self.neighbourhood().distance(a, b)
+    }
+
     fn generate_terrain(&mut self) {
         let perlin: Perlin = Perlin::new(self.seed);
+        let scale = self.config.terrain_scale;
 
         for y in 0..self.height {
             for x in 0..self.width {
-                let noise_value = perlin.get([x as f64 / TERRAIN_SCALE, y as f64 / TERRAIN_SCALE]);
-                if noise_value > THRESHOLD {
+                let noise_value = self.noise(&perlin, (x, y), scale);
+                self.set_elevation(x, y, noise_value);
+                if noise_value > self.config.threshold {
                     self.set(MapTile::new(x, y, TileType::Terrain));
                 }
             }
         }
     }
 
+    /// Biome de chaque case : les crêtes d'un bruit ridged forment les cratères,
+    /// un bruit fBm répartit le reste entre glace, plaines et champs de roches.
+    fn generate_biomes(&mut self) {
+        let noise = self.config.biomes.noises(self.seed);
+        let scale = self.config.biomes.scale;
+        let topology = self.topology();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let ridged = topology.sample(&noise.ridged, (x, y), scale, self.width, self.height);
+                let fbm = topology.sample(&noise.fbm, (x, y), scale, self.width, self.height);
+                self.set_biome(x, y, BiomeNoise::classify(ridged, fbm));
+            }
+        }
+    }
+
     fn place_resources(&mut self) {
-        let perlin = Perlin::new(self.seed);
-        let mut rng = rand::rng();
+        // Une graine distincte de celle du terrain : les gisements ne suivent pas le relief.
+        let perlin = Perlin::new(self.seed.wrapping_add(1));
+        let scale = self.config.resource_scale;
+        let resources = self.config.resources.clone();
 
         for y in 0..self.height {
             for x in 0..self.width {
@@ -69,31 +251,34 @@ impl Map {
                     continue;
                 }
 
-                let noise_value =
-                    perlin.get([x as f64 / RESOURCE_SCALE, y as f64 / RESOURCE_SCALE]);
-                if noise_value > THRESHOLD && rng.random_bool(RESOURCE_PROBABILITY) {
-                    self.set(MapTile::new(
-                        x,
-                        y,
-                        TileType::Resource(Resource::new(10, ResourceType::Energy)),
-                    ));
-                } else if noise_value > THRESHOLD && rng.random_bool(RESOURCE_PROBABILITY) {
-                    self.set(MapTile::new(
-                        x,
-                        y,
-                        TileType::Resource(Resource::new(10, ResourceType::Mineral)),
-                    ));
+                let noise_value = self.noise(&perlin, (x, y), scale);
+                if noise_value <= self.config.threshold {
+                    continue;
+                }
+
+                let density = self.biome_profile(x, y).resource_density;
+                for distribution in &resources {
+                    let probability = (distribution.probability * density).min(1.0);
+                    if self.rng.random_bool(probability) {
+                        self.set(MapTile::new(
+                            x,
+                            y,
+                            TileType::Resource(Resource::new(
+                                distribution.amount,
+                                distribution.resource_type,
+                            )),
+                        ));
+                        break;
+                    }
                 }
             }
         }
     }
 
     fn place_science_base(&mut self) {
-        let mut rng = rand::rng();
-
         loop {
-            let x = rng.random_range(1..self.width - 1);
-            let y = rng.random_range(1..self.height - 1);
+            let x = self.rng.random_range(1..self.width - 1);
+            let y = self.rng.random_range(1..self.height - 1);
 
             if self.get(x, y).tile == TileType::Empty
                 && self.get(x - 1, y).tile == TileType::Empty
@@ -108,7 +293,112 @@ impl Map {
         }
     }
 
+    /// Case affichée : le premier robot présent, sinon le terrain.
+    pub fn visible_tile(&self, x: usize, y: usize) -> TileType {
+        match self.robots_at(x, y).first() {
+            Some(&(_, robot_type)) => TileType::Robot(robot_type),
+            None => self.get(x, y).tile,
+        }
+    }
+
+    pub fn robots_at(&self, x: usize, y: usize) -> &[(usize, RobotType)] {
+        self.robots.get(&(x, y)).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn place_robot(&mut self, robot_id: usize, robot_type: RobotType, position: (usize, usize)) {
+        self.robots
+            .entry(position)
+            .or_default()
+            .push((robot_id, robot_type));
+    }
+
+    pub fn remove_robot(&mut self, robot_id: usize, position: (usize, usize)) -> Option<RobotType> {
+        let occupants = self.robots.get_mut(&position)?;
+        let index = occupants.iter().position(|&(id, _)| id == robot_id)?;
+        let (_, robot_type) = occupants.remove(index);
+        if occupants.is_empty() {
+            self.robots.remove(&position);
+        }
+        Some(robot_type)
+    }
+
+    /// Déplace un robot dans la couche des robots ; le terrain n'est pas touché.
+    pub fn move_robot(
+        &mut self,
+        robot_id: usize,
+        robot_type: RobotType,
+        from: (usize, usize),
+        to: (usize, usize),
+    ) {
+        self.remove_robot(robot_id, from);
+        self.place_robot(robot_id, robot_type, to);
+    }
+
+    fn in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Coût de planification pour entrer sur la case, `None` si elle est infranchissable
+    /// ou hors carte. Une case occupée par un robot prend le coût `robot` de la configuration.
+    pub fn movement_cost(&self, x: usize, y: usize) -> Option<f64> {
+        if !self.in_bounds(x, y) {
+            return None;
+        }
+        let costs = &self.config.movement_costs;
+        let cost = costs.cost(self.get(x, y).tile)?;
+        if self.has_blocking_robot(x, y) {
+            costs.robot
+        } else {
+            Some(cost * self.biome_profile(x, y).movement_cost)
+        }
+    }
+
+    /// Des robots occupent la case ; la base peut en accueillir plusieurs.
+    fn has_blocking_robot(&self, x: usize, y: usize) -> bool {
+        (x, y) != self.base_position && !self.robots_at(x, y).is_empty()
+    }
+
+    /// La case peut accueillir un robot, qu'elle soit libre ou non en ce moment.
+    pub fn can_stand_on(&self, x: usize, y: usize) -> bool {
+        if !self.in_bounds(x, y) {
+            return false;
+        }
+        self.config.movement_costs.cost(self.get(x, y).tile).is_some()
+    }
+
+    /// La case peut servir de destination : on s'arrête à côté si on ne peut pas s'y tenir.
+    pub fn can_target(&self, x: usize, y: usize) -> bool {
+        self.can_stand_on(x, y)
+            || matches!(
+                self.get(x, y).tile,
+                TileType::Base | TileType::Resource(_)
+            )
+    }
+
+    /// La case est prise par un robot ou déjà réclamée pendant ce tick.
+    /// La base n'est jamais considérée comme occupée.
+    pub fn is_occupied(&self, x: usize, y: usize) -> bool {
+        self.has_blocking_robot(x, y) || self.reservations.contains(&(x, y))
+    }
+
+    /// Réclame la case pour le tick en cours ; échoue si un autre robot l'a déjà prise.
+    pub fn reserve(&mut self, x: usize, y: usize) -> bool {
+        if !self.can_enter(x, y) {
+            return false;
+        }
+        (x, y) == self.base_position || self.reservations.insert((x, y))
+    }
+
+    pub fn clear_reservations(&mut self) {
+        self.reservations.clear();
+    }
+
+    /// Un robot peut entrer sur la case maintenant.
+    pub fn can_enter(&self, x: usize, y: usize) -> bool {
+        self.can_stand_on(x, y) && !self.is_occupied(x, y)
+    }
+
     pub fn is_valid(&self, x: usize, y: usize) -> bool {
-        x < self.width && y < self.height && (self.get(x, y).tile == TileType::Empty)
+        self.can_enter(x, y)
     }
 }
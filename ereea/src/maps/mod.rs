@@ -1,2 +1,7 @@
+pub mod biome;
+pub mod config;
+pub mod knowledge;
 pub mod map;
-pub mod tile;
\ No newline at end of file
+pub mod movement;
+pub mod tile;
+pub mod topology;
\ No newline at end of file
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::SQRT_2;
+
+use super::topology::Topology;
+
+/// Déplacements orthogonaux : haut, bas, gauche, droite.
+pub const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+/// Déplacements en diagonale, essayés après les déplacements orthogonaux.
+pub const DIAGONALS: [(isize, isize); 4] = [(1, 1), (1, -1), (-1, -1), (-1, 1)];
+
+/// Cases qu'un robot peut atteindre en un pas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Movement {
+    /// Quatre voisines, chaque pas coûte le prix de la case.
+    #[default]
+    FourWay,
+    /// Huit voisines ; un pas en diagonale coûte √2 fois le prix de la case
+    /// et ne peut pas couper le coin d'un obstacle.
+    EightWay,
+}
+
+impl Movement {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "four-way" => Ok(Movement::FourWay),
+            "eight-way" => Ok(Movement::EightWay),
+            _ => Err(format!(
+                "unknown movement '{}' (expected four-way or eight-way)",
+                name
+            )),
+        }
+    }
+
+    /// Directions essayées, orthogonales d'abord.
+    pub fn directions(self) -> impl Iterator<Item = (isize, isize)> {
+        let diagonals: &[(isize, isize)] = match self {
+            Movement::FourWay => &[],
+            Movement::EightWay => &DIAGONALS,
+        };
+        DIRECTIONS.into_iter().chain(diagonals.iter().copied())
+    }
+}
+
+/// Fournisseur de voisins partagé par la carte, la recherche de chemin et les stratégies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Neighbourhood {
+    pub topology: Topology,
+    pub movement: Movement,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Neighbourhood {
+    /// Case atteinte en se déplaçant de `direction` depuis `position`, selon la topologie.
+    pub fn step(
+        self,
+        position: (usize, usize),
+        direction: (isize, isize),
+    ) -> Option<(usize, usize)> {
+        self.topology
+            .step(position, direction, self.width, self.height)
+    }
+
+    /// Voisines de `position` avec le facteur de coût du pas (1 ou √2).
+    /// Une diagonale est refusée si l'une des deux cases qu'elle longe est un obstacle (`is_wall`).
+    pub fn moves<F>(
+        self,
+        position: (usize, usize),
+        is_wall: F,
+    ) -> impl Iterator<Item = ((usize, usize), f64)>
+    where
+        F: Fn((usize, usize)) -> bool,
+    {
+        self.movement.directions().filter_map(move |(dx, dy)| {
+            let next = self.step(position, (dx, dy))?;
+            if dx == 0 || dy == 0 {
+                return Some((next, 1.0));
+            }
+            let corners = [self.step(position, (dx, 0)), self.step(position, (0, dy))];
+            let cuts_corner = corners.into_iter().flatten().any(&is_wall);
+            (!cuts_corner).then_some((next, SQRT_2))
+        })
+    }
+
+    /// Voisines de `position`, orthogonales d'abord.
+    pub fn neighbours<F>(
+        self,
+        position: (usize, usize),
+        is_wall: F,
+    ) -> impl Iterator<Item = (usize, usize)>
+    where
+        F: Fn((usize, usize)) -> bool,
+    {
+        self.moves(position, is_wall).map(|(next, _)| next)
+    }
+
+    /// Cases à `radius` pas au plus de `position`, obstacles compris,
+    /// de la plus proche à la plus lointaine.
+    pub fn within(self, position: (usize, usize), radius: usize) -> Vec<(usize, usize)> {
+        let mut cells = vec![position];
+        let mut ring = 0;
+        for _ in 0..radius {
+            let end = cells.len();
+            for index in ring..end {
+                for next in self.neighbours(cells[index], |_| false) {
+                    if !cells.contains(&next) {
+                        cells.push(next);
+                    }
+                }
+            }
+            ring = end;
+        }
+        cells
+    }
+
+    /// Nombre minimal de pas entre deux cases, sans tenir compte des obstacles.
+    pub fn distance(self, from: (usize, usize), to: (usize, usize)) -> usize {
+        self.topology
+            .offsets(from, to, self.width, self.height)
+            .map(|(dx, dy)| match self.movement {
+                Movement::FourWay => dx + dy,
+                Movement::EightWay => dx.max(dy),
+            })
+            .min()
+            .unwrap_or(0)
+    }
+}
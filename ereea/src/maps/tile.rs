@@ -1,8 +1,9 @@
+use serde::{Deserialize, Serialize};
 use std::char;
 
 use crate::robots::robot::RobotType;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum TileType {
     Empty,
     Terrain,
@@ -20,34 +21,55 @@ impl TileType {
             TileType::Resource(resource) => match resource.resource_type {
                 ResourceType::Energy => '⚡',
                 ResourceType::Mineral => '💎',
+                ResourceType::Science => '🔬',
             },
             TileType::Robot(robot) => match robot {
                 RobotType::Explorer => '🚜',
                 RobotType::Harvester => '🤖',
+                RobotType::Scientist => '🧪',
+                RobotType::Relay => '📡',
             },
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct MapTile {
     pub x: usize,
     pub y: usize,
     pub tile: TileType,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ResourceType {
     Energy,
     Mineral,
+    /// Site scientifique ; `scale` est le nombre de points rapportés après analyse.
+    Science,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
     pub scale: u32,
     pub resource_type: ResourceType,
 }
 
+impl ResourceType {
+    pub const ALL: [ResourceType; 3] = [
+        ResourceType::Energy,
+        ResourceType::Mineral,
+        ResourceType::Science,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResourceType::Energy => "energy",
+            ResourceType::Mineral => "minerals",
+            ResourceType::Science => "science",
+        }
+    }
+}
+
 impl Resource {
     pub fn new(scale: u32, resource_type: ResourceType) -> Self {
         Resource { scale, resource_type }
@@ -0,0 +1,155 @@
+use noise::NoiseFn;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::{PI, TAU};
+
+/// Façon dont les bords de la carte se rejoignent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Topology {
+    /// Plan borné : on ne sort pas de la carte.
+    #[default]
+    Bounded,
+    /// Tore : chaque bord rejoint le bord opposé.
+    Torus,
+    /// Sphère approchée : les colonnes se referment comme des méridiens ;
+    /// franchir la première ou la dernière ligne fait passer par le pôle
+    /// et ressortir sur la même ligne, à l'opposé (`x + width / 2`).
+    Sphere,
+}
+
+impl Topology {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "bounded" => Ok(Topology::Bounded),
+            "torus" => Ok(Topology::Torus),
+            "sphere" => Ok(Topology::Sphere),
+            _ => Err(format!(
+                "unknown topology '{}' (expected bounded, torus or sphere)",
+                name
+            )),
+        }
+    }
+
+    /// Case atteinte depuis `(x, y)` en se déplaçant de `(dx, dy)`, ou `None` hors carte.
+    /// Les déplacements sont d'au plus une case par axe.
+    pub fn step(
+        self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+        width: usize,
+        height: usize,
+    ) -> Option<(usize, usize)> {
+        let new_x = x as isize + dx;
+        let new_y = y as isize + dy;
+        match self {
+            Topology::Bounded => {
+                let inside =
+                    (0..width as isize).contains(&new_x) && (0..height as isize).contains(&new_y);
+                inside.then_some((new_x as usize, new_y as usize))
+            }
+            Topology::Torus => Some((
+                new_x.rem_euclid(width as isize) as usize,
+                new_y.rem_euclid(height as isize) as usize,
+            )),
+            Topology::Sphere => {
+                let new_x = new_x.rem_euclid(width as isize) as usize;
+                if (0..height as isize).contains(&new_y) {
+                    Some((new_x, new_y as usize))
+                } else {
+                    Some(((new_x + width / 2) % width, y))
+                }
+            }
+        }
+    }
+
+    /// Écarts horizontaux et verticaux possibles entre deux cases, un par façon
+    /// de les relier (directement, par un bord, par un pôle).
+    pub fn offsets(
+        self,
+        from: (usize, usize),
+        to: (usize, usize),
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let wrap = |a: usize, b: usize, size: usize| {
+            let direct = a.abs_diff(b);
+            direct.min(size - direct)
+        };
+        let dy = from.1.abs_diff(to.1);
+        let candidates = match self {
+            Topology::Bounded => [Some((from.0.abs_diff(to.0), dy)), None, None],
+            Topology::Torus => [
+                Some((wrap(from.0, to.0, width), wrap(from.1, to.1, height))),
+                None,
+                None,
+            ],
+            Topology::Sphere => {
+                // Passer par un pôle retourne la carte d'une demi-largeur.
+                let across = wrap((from.0 + width / 2) % width, to.0, width);
+                [
+                    Some((wrap(from.0, to.0, width), dy)),
+                    Some((across, from.1 + to.1 + 1)),
+                    Some((across, (height - 1 - from.1) + (height - 1 - to.1) + 1)),
+                ]
+            }
+        };
+        candidates.into_iter().flatten()
+    }
+
+    /// Nombre minimal de pas entre deux cases, sans tenir compte des obstacles.
+    pub fn distance(
+        self,
+        from: (usize, usize),
+        to: (usize, usize),
+        width: usize,
+        height: usize,
+    ) -> usize {
+        self.offsets(from, to, width, height)
+            .map(|(dx, dy)| dx + dy)
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Valeur du bruit pour la case `(x, y)`, continue à travers les bords qui se rejoignent.
+    ///
+    /// Le plan borné échantillonne le plan directement. Le tore parcourt deux cercles
+    /// dans un espace à quatre dimensions, la sphère une vraie sphère en trois dimensions ;
+    /// leur rayon garde à peu près la taille des motifs donnée par `scale`.
+    pub fn sample<N>(
+        self,
+        noise: &N,
+        (x, y): (usize, usize),
+        scale: f64,
+        width: usize,
+        height: usize,
+    ) -> f64
+    where
+        N: NoiseFn<f64, 2> + NoiseFn<f64, 3> + NoiseFn<f64, 4>,
+    {
+        let (x, y) = (x as f64, y as f64);
+        let (width, height) = (width as f64, height as f64);
+        match self {
+            Topology::Bounded => noise.get([x / scale, y / scale]),
+            Topology::Torus => {
+                let (radius_x, radius_y) = (width / (TAU * scale), height / (TAU * scale));
+                let (angle_x, angle_y) = (TAU * x / width, TAU * y / height);
+                noise.get([
+                    radius_x * angle_x.cos(),
+                    radius_x * angle_x.sin(),
+                    radius_y * angle_y.cos(),
+                    radius_y * angle_y.sin(),
+                ])
+            }
+            Topology::Sphere => {
+                let radius = width / (TAU * scale);
+                let longitude = TAU * x / width;
+                let latitude = PI * (y + 0.5) / height - PI / 2.0;
+                noise.get([
+                    radius * latitude.cos() * longitude.cos(),
+                    radius * latitude.cos() * longitude.sin(),
+                    radius * latitude.sin(),
+                ])
+            }
+        }
+    }
+}
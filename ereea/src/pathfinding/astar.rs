@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::maps::map::Map;
+use crate::maps::movement::Movement;
+
+pub use crate::maps::movement::DIRECTIONS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Heuristic {
+    #[default]
+    Manhattan,
+    Octile,
+}
+
+impl Heuristic {
+    /// Estimation adaptée au modèle de déplacement : Octile dès que les diagonales sont permises.
+    pub fn for_movement(movement: Movement) -> Self {
+        match movement {
+            Movement::FourWay => Heuristic::Manhattan,
+            Movement::EightWay => Heuristic::Octile,
+        }
+    }
+
+    /// Estimation du coût restant ; la plus courte des liaisons permises par la topologie.
+    pub fn estimate(self, map: &Map, from: (usize, usize), to: (usize, usize)) -> f64 {
+        map.topology()
+            .offsets(from, to, map.width, map.height)
+            .map(|(dx, dy)| {
+                let (dx, dy) = (dx as f64, dy as f64);
+                match self {
+                    Heuristic::Manhattan => dx + dy,
+                    Heuristic::Octile => dx.max(dy) + (std::f64::consts::SQRT_2 - 1.0) * dx.min(dy),
+                }
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Node {
+    estimated: f64,
+    cost: f64,
+    position: (usize, usize),
+}
+
+impl Eq for Node {}
+
+impl Ord for Node {
+    // Tas min sur le coût estimé ; à égalité, la position départage pour rester déterministe.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimated
+            .total_cmp(&self.estimated)
+            .then_with(|| self.cost.total_cmp(&other.cost))
+            .then_with(|| other.position.cmp(&self.position))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn neighbours(
+    map: &Map,
+    position: (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    map.neighbours(position)
+}
+
+/// Cherche le chemin le moins coûteux de `start` à `goal` selon les coûts de la carte.
+///
+/// Le chemin retourné commence par `start` et finit par `goal`. Il suffit que `goal`
+/// soit une cible valide (`Map::can_target`) : une ressource peut être visée sans y marcher.
+/// Les cases occupées par d'autres robots sont traversables avec un surcoût, les montées et les biomes difficiles aussi.
+pub fn find_path(
+    map: &Map,
+    start: (usize, usize),
+    goal: (usize, usize),
+    heuristic: Heuristic,
+) -> Option<Vec<(usize, usize)>> {
+    find_path_avoiding(map, start, goal, heuristic, &[])
+}
+
+/// Comme `find_path`, sans passer par les cases de `avoid` (sauf si c'est `goal`).
+pub fn find_path_avoiding(
+    map: &Map,
+    start: (usize, usize),
+    goal: (usize, usize),
+    heuristic: Heuristic,
+    avoid: &[(usize, usize)],
+) -> Option<Vec<(usize, usize)>> {
+    if !map.can_target(goal.0, goal.1) {
+        return None;
+    }
+
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut best_cost: HashMap<(usize, usize), f64> = HashMap::new();
+
+    best_cost.insert(start, 0.0);
+    open.push(Node {
+        estimated: heuristic.estimate(map, start, goal),
+        cost: 0.0,
+        position: start,
+    });
+
+    while let Some(Node { cost, position, .. }) = open.pop() {
+        if position == goal {
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(&previous) = came_from.get(&current) {
+                path.push(previous);
+                current = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if cost > best_cost[&position] {
+            continue;
+        }
+
+        for (next, factor) in map.moves(position) {
+            if next != goal && avoid.contains(&next) {
+                continue;
+            }
+            let step_cost = if next == goal {
+                map.movement_cost(next.0, next.1).unwrap_or(1.0) * factor
+            } else {
+                match map.movement_cost(next.0, next.1) {
+                    Some(step_cost) => step_cost * factor,
+                    None => continue,
+                }
+            };
+            // Chaque tick d'attente (escalade, terrain difficile) compte comme un pas de plus.
+            let next_cost = cost + step_cost + map.step_penalty(position, next) as f64;
+            if best_cost.get(&next).is_none_or(|&known| next_cost < known) {
+                best_cost.insert(next, next_cost);
+                came_from.insert(next, position);
+                open.push(Node {
+                    estimated: next_cost + heuristic.estimate(map, next, goal),
+                    cost: next_cost,
+                    position: next,
+                });
+            }
+        }
+    }
+
+    None
+}
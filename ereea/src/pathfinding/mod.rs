@@ -0,0 +1,2 @@
+pub mod astar;
+pub mod path_cache;
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use crate::maps::map::Map;
+
+use super::astar::{Heuristic, find_path, find_path_avoiding};
+
+/// Ticks passés à attendre qu'un robot libère la prochaine case avant de le contourner.
+pub const PATIENCE: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Step {
+    /// Prochaine case du chemin.
+    Move((usize, usize)),
+    /// Le robot est sur la cible ou juste à côté.
+    Arrived,
+    Unreachable,
+}
+
+/// Chemin mémorisé par un robot vers sa cible courante.
+///
+/// Le chemin n'est recalculé que si la cible change ou si la prochaine case est bloquée.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PathCache {
+    target: Option<(usize, usize)>,
+    steps: VecDeque<(usize, usize)>,
+    /// Ticks d'affilée où la prochaine case était occupée par un robot.
+    #[serde(default)]
+    waited: u32,
+}
+
+impl PathCache {
+    pub fn clear(&mut self) {
+        self.target = None;
+        self.steps.clear();
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.steps.len()
+    }
+
+    fn is_usable(&self, position: (usize, usize), target: (usize, usize), map: &Map) -> bool {
+        if self.target != Some(target) {
+            return false;
+        }
+        match self.steps.front() {
+            Some(&next) if next == target => true,
+            Some(&next) => map.is_adjacent(position, next) && map.can_enter(next.0, next.1),
+            None => false,
+        }
+    }
+
+    pub fn next_step(
+        &mut self,
+        position: (usize, usize),
+        target: (usize, usize),
+        map: &Map,
+    ) -> Step {
+        if position == target {
+            self.clear();
+            return Step::Arrived;
+        }
+        if self.steps.front() == Some(&position) {
+            self.steps.pop_front();
+        }
+
+        let blocked = self
+            .steps
+            .front()
+            .is_some_and(|&(x, y)| (x, y) != target && map.is_occupied(x, y));
+        self.waited = if blocked { self.waited + 1 } else { 0 };
+
+        if !self.is_usable(position, target, map) {
+            // Après trop d'attente, on contourne les robots voisins : deux robots
+            // face à face ne s'attendent pas indéfiniment.
+            let avoid: Vec<_> = if self.waited >= PATIENCE {
+                map.neighbours(position)
+                    .filter(|&(x, y)| (x, y) != target && map.is_occupied(x, y))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            let heuristic = Heuristic::for_movement(map.movement());
+            match find_path_avoiding(map, position, target, heuristic, &avoid)
+                .or_else(|| find_path(map, position, target, heuristic))
+            {
+                Some(path) => {
+                    self.target = Some(target);
+                    self.steps = path.into_iter().skip(1).collect();
+                }
+                None => {
+                    self.clear();
+                    return Step::Unreachable;
+                }
+            }
+        }
+
+        match self.steps.front() {
+            Some(&next) if self.steps.len() > 1 => Step::Move(next),
+            _ => Step::Arrived,
+        }
+    }
+}
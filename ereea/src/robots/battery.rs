@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// Caractéristiques de la batterie donnée à chaque robot construit.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BatteryConfig {
+    pub capacity: u32,
+    /// Consommation d'un déplacement d'une case.
+    pub move_cost: u32,
+    /// Consommation d'une action sur place (récolte, tick d'analyse, relevé).
+    pub action_cost: u32,
+    /// Marge gardée en plus du trajet estimé avant de forcer le retour à la base.
+    pub reserve: u32,
+}
+
+impl Default for BatteryConfig {
+    fn default() -> Self {
+        BatteryConfig {
+            capacity: 200,
+            move_cost: 1,
+            action_cost: 2,
+            reserve: 10,
+        }
+    }
+}
+
+/// Batterie d'un robot, rechargée entièrement à chaque passage à la base.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Battery {
+    pub config: BatteryConfig,
+    pub charge: u32,
+}
+
+impl Default for Battery {
+    fn default() -> Self {
+        Battery::new(BatteryConfig::default())
+    }
+}
+
+impl Battery {
+    pub fn new(config: BatteryConfig) -> Self {
+        Battery {
+            config,
+            charge: config.capacity,
+        }
+    }
+
+    /// Consomme `amount` si la charge suffit ; sinon ne consomme rien et retourne `false`.
+    pub fn consume(&mut self, amount: u32) -> bool {
+        if self.charge < amount {
+            return false;
+        }
+        self.charge -= amount;
+        true
+    }
+
+    pub fn can_move(&self) -> bool {
+        self.charge >= self.config.move_cost
+    }
+
+    /// La charge ne permet plus le moindre déplacement.
+    pub fn is_depleted(&self) -> bool {
+        !self.can_move()
+    }
+
+    /// La charge ne couvre plus un trajet de `distance` cases, marge comprise.
+    pub fn is_low(&self, distance: usize) -> bool {
+        let needed = distance as u32 * self.config.move_cost + self.config.reserve;
+        self.charge <= needed
+    }
+
+    pub fn recharge(&mut self) {
+        self.charge = self.config.capacity;
+    }
+}
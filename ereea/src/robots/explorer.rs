@@ -1,27 +1,60 @@
+use super::battery::Battery;
 use super::robot::{Robot, RobotState, RobotType};
+use super::strategies::{Action, BuiltinStrategy, StrategyKind};
 use crate::maps::{
+    knowledge::KnowledgeMap,
     map::Map,
-    tile::{Resource, TileType},
+    tile::{Resource, ResourceType, TileType},
 };
-use rand::Rng;
+use crate::pathfinding::path_cache::PathCache;
+use crate::simulation::events::SimulationEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Conditions de retour des explorateurs envoyés depuis la base.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExplorerConfig {
+    /// Nombre de ressources à signaler avant de rentrer.
+    pub findings_to_report: usize,
+    /// Nombre maximal de ticks hors de la base avant de rentrer.
+    pub max_ticks_out: Option<u32>,
+}
+
+impl Default for ExplorerConfig {
+    fn default() -> Self {
+        ExplorerConfig {
+            findings_to_report: 3,
+            max_ticks_out: None,
+        }
+    }
+}
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Explorer {
     id: usize,
     x: usize,
     y: usize,
-    resource: Option<(usize, usize, Resource, bool)>,
+    config: ExplorerConfig,
+    /// Ressources repérées depuis le départ de la base, à signaler au retour.
+    findings: Vec<(usize, usize, Resource)>,
+    /// Ressources déjà signalées, à la base ou par radio.
+    reported: HashSet<(usize, usize)>,
+    strategy: BuiltinStrategy,
+    knowledge: KnowledgeMap,
+    ticks_out: u32,
     state: RobotState,
+    path: PathCache,
+    battery: Battery,
+    #[serde(default)]
+    climbing: u32,
+    #[serde(skip)]
+    events: Vec<SimulationEvent>,
 }
 
 impl Robot for Explorer {
     fn new(x: usize, y: usize, id: usize) -> Self {
-        Explorer {
-            id: id,
-            x: x,
-            y: y,
-            resource: None,
-            state: RobotState::Exploring,
-        }
+        Explorer::with_config(x, y, id, ExplorerConfig::default())
     }
 
     fn get_type(&self) -> RobotType {
@@ -37,7 +70,7 @@ impl Robot for Explorer {
     }
 
     fn get_state(&self) -> RobotState {
-        self.state.clone()
+        self.state
     }
 
     fn set_state(&mut self, state: RobotState) {
@@ -62,37 +95,140 @@ impl Robot for Explorer {
     }
 
     fn get_current_resource(&self) -> Option<(usize, usize, Resource, bool)> {
-        self.resource
+        self.findings
+            .first()
+            .map(|&(x, y, resource)| (x, y, resource, true))
     }
 
     fn set_target_resource(&mut self, _: Option<(usize, usize, Resource, bool)>) {}
+
+    fn take_findings(&mut self) -> Vec<(usize, usize, Resource)> {
+        self.reported
+            .extend(self.findings.iter().map(|&(x, y, _)| (x, y)));
+        std::mem::take(&mut self.findings)
+    }
+
+    fn pending_events(&mut self) -> &mut Vec<SimulationEvent> {
+        &mut self.events
+    }
+
+    fn path_cache(&mut self) -> &mut PathCache {
+        &mut self.path
+    }
+
+    fn get_battery(&self) -> Battery {
+        self.battery
+    }
+
+    fn battery(&mut self) -> &mut Battery {
+        &mut self.battery
+    }
+
+    fn climbing(&mut self) -> &mut u32 {
+        &mut self.climbing
+    }
+
+    fn strategy(&mut self) -> &mut BuiltinStrategy {
+        &mut self.strategy
+    }
+
+    fn knowledge(&mut self) -> &mut KnowledgeMap {
+        &mut self.knowledge
+    }
+
+    fn wanted_resources(&self) -> Vec<ResourceType> {
+        ResourceType::ALL.to_vec()
+    }
 }
 
 impl Explorer {
-    pub fn explore(&mut self, map: &mut Map) {
-        let mut rng = rand::rng();
+    pub fn with_config(x: usize, y: usize, id: usize, config: ExplorerConfig) -> Self {
+        Explorer {
+            id,
+            x,
+            y,
+            config,
+            findings: Vec::new(),
+            reported: HashSet::new(),
+            strategy: StrategyKind::Frontier.build(),
+            knowledge: KnowledgeMap::default(),
+            ticks_out: 0,
+            state: RobotState::Exploring,
+            path: PathCache::default(),
+            battery: Battery::default(),
+            climbing: 0,
+            events: Vec::new(),
+        }
+    }
 
-        let move_horizontal = rng.random_bool(0.5);
+    pub fn config(&self) -> ExplorerConfig {
+        self.config
+    }
+
+    pub fn get_strategy(&self) -> &BuiltinStrategy {
+        &self.strategy
+    }
 
-        let (direction_x, direction_y) = if move_horizontal {
-            (if rng.random_bool(0.5) { 1isize } else { -1isize }, 0isize)
-        } else {
-            (0isize, if rng.random_bool(0.5) { 1isize } else { -1isize })
-        };
+    pub fn findings(&self) -> &[(usize, usize, Resource)] {
+        &self.findings
+    }
 
-        let new_x = (self.x as isize + direction_x).max(0) as usize;
-        let new_y = (self.y as isize + direction_y).max(0) as usize;
+    /// Relève les ressources visibles puis applique l'action choisie par la stratégie.
+    /// Rentre une fois assez de ressources repérées, le budget de temps épuisé,
+    /// ou dès qu'une ressource est touchée (action `Work`).
+    pub fn explore(&mut self, map: &mut Map) {
+        self.ticks_out += 1;
+        self.observe(map);
+
+        let enough_findings = self.findings.len() >= self.config.findings_to_report;
+        let out_of_time = self
+            .config
+            .max_ticks_out
+            .is_some_and(|max_ticks| self.ticks_out >= max_ticks);
+        if enough_findings || out_of_time {
+            self.set_state(RobotState::ReturningToBase);
+            return;
+        }
 
-        if new_x <= map.width && new_y <= map.height {
-            match map.get(new_x, new_y).tile {
-                TileType::Resource(resource) => {
-                    self.resource = Some((new_x, new_y, resource, true));
-                    self.set_state(RobotState::ReturningToBase);
-                }
-                _ => {
-                    self.move_to(new_x, new_y, map);
+        match self.decide(map) {
+            Action::MoveTo(target) => self.head_to(target, map),
+            Action::Work((x, y)) => {
+                let adjacent = map.is_adjacent(self.get_position(), (x, y));
+                match map.get(x, y).tile {
+                    TileType::Resource(resource) if adjacent => {
+                        if self.spend_action() {
+                            self.record_finding(x, y, resource);
+                            self.set_state(RobotState::ReturningToBase);
+                        }
+                    }
+                    _ => self.head_to((x, y), map),
                 }
             }
+            Action::ReturnToBase => self.set_state(RobotState::ReturningToBase),
+            Action::Wait => {}
+        }
+    }
+
+    /// Relève les ressources à portée de vue du robot.
+    fn observe(&mut self, map: &Map) {
+        for tile in self.perceive(map).visible {
+            if let TileType::Resource(resource) = tile.tile {
+                self.record_finding(tile.position.0, tile.position.1, resource);
+            }
+        }
+    }
+
+    fn record_finding(&mut self, x: usize, y: usize, resource: Resource) {
+        if self.reported.contains(&(x, y))
+            || self.findings.iter().any(|&(fx, fy, _)| (fx, fy) == (x, y))
+        {
+            return;
         }
+        self.findings.push((x, y, resource));
+        self.events.push(SimulationEvent::ResourceDiscovered {
+            robot_id: self.id,
+            position: (x, y),
+            resource,
+        });
     }
 }
@@ -1,9 +1,19 @@
+use super::battery::Battery;
 use super::robot::{Robot, RobotState, RobotType};
+use super::strategies::{BuiltinStrategy, StrategyKind};
 use crate::maps::{
+    knowledge::KnowledgeMap,
     map::Map,
-    tile::{MapTile, Resource, TileType},
+    tile::{MapTile, Resource, ResourceType, TileType},
 };
+use crate::pathfinding::path_cache::PathCache;
+use crate::simulation::events::SimulationEvent;
+use crate::simulation::messages::Order;
+use serde::{Deserialize, Serialize};
 
+pub const DEFAULT_CARGO_CAPACITY: u32 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Harvester {
     id: usize,
     x: usize,
@@ -11,18 +21,21 @@ pub struct Harvester {
     cargo_capacity: u32,
     state: RobotState,
     target_resource: Option<(usize, usize, Resource, bool)>,
+    /// Ressources transportées jusqu'au prochain passage à la base.
+    cargo: Option<Resource>,
+    path: PathCache,
+    battery: Battery,
+    #[serde(default)]
+    climbing: u32,
+    strategy: BuiltinStrategy,
+    knowledge: KnowledgeMap,
+    #[serde(skip)]
+    events: Vec<SimulationEvent>,
 }
 
 impl Robot for Harvester {
     fn new(x: usize, y: usize, id: usize) -> Self {
-        Harvester {
-            id: id,
-            x: x,
-            y: y,
-            cargo_capacity: 5,
-            state: RobotState::Harvesting,
-            target_resource: None,
-        }
+        Harvester::with_cargo_capacity(x, y, id, DEFAULT_CARGO_CAPACITY)
     }
     fn get_id(&self) -> usize {
         self.id
@@ -36,7 +49,7 @@ impl Robot for Harvester {
     }
 
     fn get_state(&self) -> RobotState {
-        self.state.clone()
+        self.state
     }
 
     fn set_state(&mut self, state: RobotState) {
@@ -60,10 +73,63 @@ impl Robot for Harvester {
         self.target_resource = target;
     }
 
+    fn pending_events(&mut self) -> &mut Vec<SimulationEvent> {
+        &mut self.events
+    }
+
+    fn path_cache(&mut self) -> &mut PathCache {
+        &mut self.path
+    }
+
+    fn get_battery(&self) -> Battery {
+        self.battery
+    }
+
+    fn battery(&mut self) -> &mut Battery {
+        &mut self.battery
+    }
+
+    fn climbing(&mut self) -> &mut u32 {
+        &mut self.climbing
+    }
+
+    fn strategy(&mut self) -> &mut BuiltinStrategy {
+        &mut self.strategy
+    }
+
+    fn knowledge(&mut self) -> &mut KnowledgeMap {
+        &mut self.knowledge
+    }
+
+    fn wanted_resources(&self) -> Vec<ResourceType> {
+        vec![ResourceType::Energy, ResourceType::Mineral]
+    }
+
+    fn receive(&mut self, order: Order) {
+        match order {
+            Order::HarvestRequest { position, resource } => {
+                self.set_target_resource(Some((position.0, position.1, resource, true)));
+                self.set_state(RobotState::Harvesting);
+            }
+            Order::Cancel => {
+                self.set_target_resource(None);
+                self.set_state(RobotState::ReturningToBase);
+            }
+        }
+    }
+
+    fn take_cargo(&mut self) -> Option<Resource> {
+        self.cargo.take()
+    }
+
     fn update(&mut self, map: &mut Map) {
         match self.state {
             RobotState::Harvesting => {
-                self.harvest(map);
+                if self.target_resource.is_some() {
+                    self.harvest(map);
+                } else {
+                    self.search(map);
+                }
             }
             RobotState::ReturningToBase => {
                 self.return_to_base(map);
@@ -74,6 +140,24 @@ impl Robot for Harvester {
 }
 
 impl Harvester {
+    pub fn with_cargo_capacity(x: usize, y: usize, id: usize, cargo_capacity: u32) -> Self {
+        Harvester {
+            id,
+            x,
+            y,
+            cargo_capacity,
+            state: RobotState::Harvesting,
+            target_resource: None,
+            cargo: None,
+            path: PathCache::default(),
+            battery: Battery::default(),
+            climbing: 0,
+            strategy: StrategyKind::NearestResource.build(),
+            knowledge: KnowledgeMap::default(),
+            events: Vec::new(),
+        }
+    }
+
     pub fn harvest(&mut self, map: &mut Map) {
         if let Some((x, y, _, _)) = self.target_resource {
             let step = self.calculate_next_step(x, y, map);
@@ -83,34 +167,46 @@ impl Harvester {
                 }
                 None => {
                     let tile = map.get(x, y);
-                    match tile.tile {
-                        TileType::Resource(res) => {
-                            if res.scale > self.cargo_capacity {
-                                self.set_target_resource(Some((
-                                    x,
-                                    y,
-                                    Resource::new(self.cargo_capacity, res.resource_type),
-                                    true,
-                                )));
-                                map.set(MapTile::new(
-                                    x,
-                                    y,
-                                    TileType::Resource(Resource::new(
-                                        res.scale - self.cargo_capacity,
-                                        res.resource_type,
-                                    )),
-                                ));
-                            } else {
-                                self.set_target_resource(Some((
-                                    x,
-                                    y,
-                                    Resource::new(res.scale, res.resource_type),
-                                    false,
-                                )));
-                                map.set(MapTile::new(x, y, TileType::Empty));
-                            }
+                    if let TileType::Resource(res) = tile.tile {
+                        if !self.spend_action() {
+                            return;
+                        }
+                        let amount = res.scale.min(self.cargo_capacity);
+                        self.cargo = Some(Resource::new(amount, res.resource_type));
+                        self.events.push(SimulationEvent::Harvested {
+                            robot_id: self.id,
+                            position: (x, y),
+                            resource_type: res.resource_type,
+                            amount,
+                            remaining: res.scale - amount,
+                        });
+                        if res.scale > self.cargo_capacity {
+                            self.set_target_resource(Some((
+                                x,
+                                y,
+                                Resource::new(self.cargo_capacity, res.resource_type),
+                                true,
+                            )));
+                            map.set(MapTile::new(
+                                x,
+                                y,
+                                TileType::Resource(Resource::new(
+                                    res.scale - self.cargo_capacity,
+                                    res.resource_type,
+                                )),
+                            ));
+                        } else {
+                            self.set_target_resource(Some((
+                                x,
+                                y,
+                                Resource::new(res.scale, res.resource_type),
+                                false,
+                            )));
+                            map.set(MapTile::new(x, y, TileType::Empty));
                         }
-                        _ => {}
+                    } else {
+                        // Site déjà vidé par un autre récolteur.
+                        self.set_target_resource(None);
                     }
                     self.set_state(RobotState::ReturningToBase);
                 }
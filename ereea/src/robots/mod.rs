@@ -1,3 +1,7 @@
 pub mod robot;
+pub mod battery;
 pub mod explorer;
-pub mod harvester;
\ No newline at end of file
+pub mod harvester;
+pub mod relay;
+pub mod scientist;
+pub mod strategies;
\ No newline at end of file
@@ -0,0 +1,140 @@
+use super::battery::Battery;
+use super::robot::{Robot, RobotState, RobotType};
+use super::strategies::{BuiltinStrategy, StrategyKind};
+use crate::maps::{
+    knowledge::KnowledgeMap,
+    map::Map,
+    tile::{Resource, ResourceType},
+};
+use crate::pathfinding::path_cache::PathCache;
+use crate::simulation::events::SimulationEvent;
+use serde::{Deserialize, Serialize};
+
+/// Robot qui étend la portée radio de la base.
+/// Envoyé vers un poste, il s'y gare et n'y consomme plus de batterie ;
+/// sans poste, il se déplace selon sa stratégie.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Relay {
+    id: usize,
+    x: usize,
+    y: usize,
+    post: Option<(usize, usize)>,
+    state: RobotState,
+    path: PathCache,
+    battery: Battery,
+    #[serde(default)]
+    climbing: u32,
+    strategy: BuiltinStrategy,
+    knowledge: KnowledgeMap,
+    #[serde(skip)]
+    events: Vec<SimulationEvent>,
+}
+
+impl Robot for Relay {
+    fn new(x: usize, y: usize, id: usize) -> Self {
+        Relay {
+            id,
+            x,
+            y,
+            post: None,
+            state: RobotState::Relaying,
+            path: PathCache::default(),
+            battery: Battery::default(),
+            climbing: 0,
+            strategy: StrategyKind::Spiral.build(),
+            knowledge: KnowledgeMap::default(),
+            events: Vec::new(),
+        }
+    }
+
+    fn get_id(&self) -> usize {
+        self.id
+    }
+
+    fn get_type(&self) -> RobotType {
+        RobotType::Relay
+    }
+
+    fn get_position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    fn get_state(&self) -> RobotState {
+        self.state
+    }
+
+    fn set_state(&mut self, state: RobotState) {
+        self.state = state;
+    }
+
+    fn set_position(&mut self, x: usize, y: usize) {
+        self.x = x;
+        self.y = y;
+    }
+
+    fn get_current_resource(&self) -> Option<(usize, usize, Resource, bool)> {
+        None
+    }
+
+    fn set_target_resource(&mut self, _target: Option<(usize, usize, Resource, bool)>) {}
+
+    fn pending_events(&mut self) -> &mut Vec<SimulationEvent> {
+        &mut self.events
+    }
+
+    fn path_cache(&mut self) -> &mut PathCache {
+        &mut self.path
+    }
+
+    fn get_battery(&self) -> Battery {
+        self.battery
+    }
+
+    fn battery(&mut self) -> &mut Battery {
+        &mut self.battery
+    }
+
+    fn climbing(&mut self) -> &mut u32 {
+        &mut self.climbing
+    }
+
+    fn strategy(&mut self) -> &mut BuiltinStrategy {
+        &mut self.strategy
+    }
+
+    fn knowledge(&mut self) -> &mut KnowledgeMap {
+        &mut self.knowledge
+    }
+
+    fn wanted_resources(&self) -> Vec<ResourceType> {
+        Vec::new()
+    }
+
+    fn update(&mut self, map: &mut Map) {
+        match self.state {
+            RobotState::Relaying => match self.post {
+                Some(post) => self.head_to(post, map),
+                None => self.search(map),
+            },
+            RobotState::ReturningToBase => {
+                self.return_to_base(map);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Relay {
+    pub fn post(&self) -> Option<(usize, usize)> {
+        self.post
+    }
+
+    pub fn set_post(&mut self, post: Option<(usize, usize)>) {
+        self.post = post;
+    }
+
+    /// Garé à son poste.
+    pub fn is_parked(&self) -> bool {
+        self.post == Some((self.x, self.y))
+    }
+}
@@ -1,26 +1,44 @@
-use std::{
-    any::Any,
-    collections::{HashMap, VecDeque},
-};
+use rand::Rng;
+use std::any::Any;
+
+use serde::{Deserialize, Serialize};
 
 use crate::maps::{
+    knowledge::{KnowledgeMap, KnownTile, MergeReport},
     map::Map,
-    tile::{MapTile, Resource, TileType},
+    tile::{Resource, ResourceType, TileType},
 };
+use super::battery::Battery;
+use super::strategies::{Action, BuiltinStrategy, Perception, Strategy, VisibleTile};
+use crate::pathfinding::astar::neighbours;
+use crate::pathfinding::path_cache::{PathCache, Step};
+use crate::simulation::events::SimulationEvent;
+use crate::simulation::messages::Order;
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
 pub enum RobotType {
     Explorer,
     Harvester,
+    Scientist,
+    /// Relaie les messages radio entre les robots et la base.
+    Relay,
 }
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
 pub enum RobotState {
     Exploring,
     Harvesting,
+    /// Le robot se rend sur un site scientifique et l'analyse.
+    Analyzing,
     ReturningToBase,
     Reporting,
     Idle,
+    /// Garé à la base, en attente d'un ordre du répartiteur.
+    Standby,
+    /// Le relais rejoint son poste, ou y reste, et relaie les messages radio.
+    Relaying,
+    /// Batterie vide loin de la base : le robot reste immobile sur la carte.
+    Stranded,
 }
 
 pub trait Robot: Any {
@@ -35,103 +53,227 @@ pub trait Robot: Any {
     fn update(&mut self, map: &mut Map);
     fn get_current_resource(&self) -> Option<(usize, usize, Resource, bool)>;
     fn set_target_resource(&mut self, target_resource: Option<(usize, usize, Resource, bool)>);
+    /// Événements produits par le robot depuis le dernier relevé de la simulation.
+    fn pending_events(&mut self) -> &mut Vec<SimulationEvent>;
+    fn path_cache(&mut self) -> &mut PathCache;
+    fn get_battery(&self) -> Battery;
+    fn battery(&mut self) -> &mut Battery;
+    /// Ticks d'escalade déjà passés vers la prochaine case.
+    fn climbing(&mut self) -> &mut u32;
+    fn strategy(&mut self) -> &mut BuiltinStrategy;
+    /// Types de ressource que ce robot sait exploiter.
+    fn wanted_resources(&self) -> Vec<ResourceType>;
 
-    fn move_to(&mut self, x: usize, y: usize, map: &mut Map) {
-        if map.is_valid(x, y) {
-            let (old_x, old_y) = self.get_position();
-            let (base_x, base_y) = map.base_position;
+    /// Carte partielle de ce que le robot a observé ou appris à la base.
+    fn knowledge(&mut self) -> &mut KnowledgeMap;
+
+    fn set_strategy(&mut self, strategy: BuiltinStrategy) {
+        *self.strategy() = strategy;
+    }
+
+    /// Les cases à portée de vue du robot, la sienne d'abord.
+    fn perceive(&self, map: &Map) -> Perception {
+        let position = self.get_position();
+        let visible = map
+            .visible_from(position)
+            .into_iter()
+            .map(|(x, y)| VisibleTile {
+                position: (x, y),
+                tile: map.get(x, y).tile,
+                standable: map.can_stand_on(x, y),
+            })
+            .collect();
+        Perception {
+            position,
+            base_position: map.base_position,
+            topology: map.topology(),
+            movement: map.movement(),
+            width: map.width,
+            height: map.height,
+            visible,
+            wanted: self.wanted_resources(),
+        }
+    }
+
+    /// Demande à la stratégie du robot l'action de ce tick.
+    fn decide(&mut self, map: &mut Map) -> Action {
+        let perception = self.perceive(map);
+        self.strategy().decide(&perception, &mut map.rng)
+    }
+
+    /// Avance d'une case vers `target` ; une case voisine est visée directement.
+    fn head_to(&mut self, target: (usize, usize), map: &mut Map) {
+        let position = self.get_position();
+        if position == target {
+            return;
+        }
+        let adjacent = map.is_adjacent(position, target);
+        let (x, y) = if adjacent {
+            target
+        } else {
+            match self.calculate_next_step(target.0, target.1, map) {
+                Some(next) => next,
+                None => return,
+            }
+        };
+        if map.can_enter(x, y) {
+            self.move_to(x, y, map);
+        }
+    }
+
+    /// Note dans sa carte ce que le robot voit à ce tick.
+    fn remember(&mut self, map: &Map, tick: u64) {
+        if self.knowledge().width != map.width || self.knowledge().height != map.height {
+            *self.knowledge() = KnowledgeMap::new(map.width, map.height);
+        }
+        for tile in self.perceive(map).visible {
+            let (x, y) = tile.position;
+            self.knowledge().observe(
+                x,
+                y,
+                KnownTile {
+                    tile: tile.tile,
+                    standable: tile.standable,
+                    tick,
+                },
+            );
+        }
+    }
+
+    /// À la base : échange avec la carte de la station, puis la stratégie
+    /// tient compte de tout ce qui est désormais connu.
+    fn sync_knowledge(&mut self, station: &mut KnowledgeMap) -> MergeReport {
+        let report = self.knowledge().sync(station);
+        let wanted = self.wanted_resources();
+        self.strategy().learn(station, &wanted);
+        report
+    }
 
-            if old_x == base_x && old_y == base_y {
-                map.set(MapTile::new(old_x, old_y, TileType::Base));
-            } else {
-                map.set(MapTile::new(old_x, old_y, TileType::Empty));
+    /// Sans cible imposée par la base, le robot suit sa stratégie ;
+    /// la ressource qu'elle désigne devient sa cible.
+    fn search(&mut self, map: &mut Map) {
+        match self.decide(map) {
+            Action::MoveTo(target) => self.head_to(target, map),
+            Action::Work((x, y)) => {
+                if let TileType::Resource(resource) = map.get(x, y).tile {
+                    self.set_target_resource(Some((x, y, resource, true)));
+                }
             }
+            Action::ReturnToBase => self.set_state(RobotState::ReturningToBase),
+            Action::Wait => {}
+        }
+    }
 
-            map.set(MapTile::new(x, y, TileType::Robot(self.get_type())));
+    /// Ordre reçu de la base. Par défaut, le robot l'ignore.
+    fn receive(&mut self, _order: Order) {}
+
+    /// Ressources repérées à signaler à la base ; le robot les oublie ensuite.
+    fn take_findings(&mut self) -> Vec<(usize, usize, Resource)> {
+        Vec::new()
+    }
+
+    /// Chargement rapporté à la base ; le robot en est vidé.
+    fn take_cargo(&mut self) -> Option<Resource> {
+        None
+    }
+
+    /// Force le retour à la base quand la charge ne couvre plus le trajet estimé
+    /// (distance de Manhattan, sans tenir compte des obstacles).
+    fn check_battery(&mut self, map: &Map) {
+        if !matches!(
+            self.get_state(),
+            RobotState::Exploring
+                | RobotState::Harvesting
+                | RobotState::Analyzing
+                | RobotState::Relaying
+        ) {
+            return;
+        }
+        let distance = map.distance(self.get_position(), map.base_position);
+        if self.get_battery().is_low(distance) {
+            self.set_state(RobotState::ReturningToBase);
+        }
+    }
+
+    /// Risque du biome : hors de la base, le robot subit un incident avec la probabilité
+    /// de sa case et perd `hazard_drain` de charge.
+    fn endure_hazard(&mut self, map: &mut Map) {
+        let (x, y) = self.get_position();
+        let rate = map.hazard_rate(x, y);
+        if (x, y) == map.base_position || rate <= 0.0 || !map.rng.random_bool(rate) {
+            return;
+        }
+        let drain = map.config.biomes.hazard_drain.min(self.get_battery().charge);
+        self.battery().consume(drain);
+        let event = SimulationEvent::HazardStruck {
+            robot_id: self.get_id(),
+            position: (x, y),
+            drain,
+        };
+        self.pending_events().push(event);
+    }
+
+    /// Consomme le coût d'une action sur place ; `false` si la charge ne suffit pas.
+    fn spend_action(&mut self) -> bool {
+        let cost = self.get_battery().config.action_cost;
+        self.battery().consume(cost)
+    }
+
+    /// Déplace le robot s'il peut réclamer la case ; retourne `false` s'il doit attendre.
+    fn move_to(&mut self, x: usize, y: usize, map: &mut Map) -> bool {
+        if !map.can_stand_on(x, y) {
+            eprintln!("Invalid move to position ({}, {})", x, y);
+            return false;
+        }
+        if !self.get_battery().can_move() {
+            return false;
+        }
+        // Une montée ou un terrain difficile se franchit en plusieurs ticks,
+        // chacun au prix d'un déplacement.
+        if *self.climbing() < map.step_penalty(self.get_position(), (x, y)) {
+            let cost = self.get_battery().config.move_cost;
+            self.battery().consume(cost);
+            *self.climbing() += 1;
+            return false;
+        }
+        if map.reserve(x, y) {
+            *self.climbing() = 0;
+            let (old_x, old_y) = self.get_position();
+            let cost = self.get_battery().config.move_cost;
+            self.battery().consume(cost);
+            map.move_robot(self.get_id(), self.get_type(), (old_x, old_y), (x, y));
 
             self.set_position(x, y);
+            let event = SimulationEvent::RobotMoved {
+                robot_id: self.get_id(),
+                robot_type: self.get_type(),
+                from: (old_x, old_y),
+                to: (x, y),
+            };
+            self.pending_events().push(event);
+            true
         } else {
-            eprintln!("Invalid move to position ({}, {})", x, y);
+            false
         }
     }
 
+    /// Prochaine case vers la cible, ou `None` une fois à côté de la cible.
+    /// Si la cible est inaccessible, le robot se rabat sur la première case voisine libre.
     fn calculate_next_step(
-        &self,
+        &mut self,
         target_x: usize,
         target_y: usize,
         map: &Map,
     ) -> Option<(usize, usize)> {
-        let (start_x, start_y) = self.get_position();
-        if start_x == target_x && start_y == target_y {
-            return None;
-        }
-    
-        let mut queue = VecDeque::new();
-        let mut came_from = HashMap::new();
-    
-        queue.push_back((start_x, start_y));
-        came_from.insert((start_x, start_y), None);
-    
-        while let Some((x, y)) = queue.pop_front() {
-            if x == target_x && y == target_y {
-                break;
-            }
-    
-            // Se déplacer uniquement en ligne droite (haut, bas, gauche, droite)
-            for &(dx, dy) in &[(0, 1), (1, 0), (0, -1), (-1, 0)] {
-                let new_x = x as isize + dx;
-                let new_y = y as isize + dy;
-    
-                if new_x < 0 || new_y < 0 {
-                    continue;
-                }
-    
-                let new_x = new_x as usize;
-                let new_y = new_y as usize;
-    
-                if map.is_valid(new_x, new_y) && !came_from.contains_key(&(new_x, new_y)) {
-                    queue.push_back((new_x, new_y));
-                    came_from.insert((new_x, new_y), Some((x, y)));
-                }
+        let position = self.get_position();
+        match self.path_cache().next_step(position, (target_x, target_y), map) {
+            Step::Move(next) => Some(next),
+            Step::Arrived => None,
+            Step::Unreachable => {
+                neighbours(map, position).find(|&(x, y)| map.is_valid(x, y))
             }
         }
-    
-        if !came_from.contains_key(&(target_x, target_y)) {
-            // Essayer toutes les directions possibles jusqu'à en trouver une valide
-            let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
-            for &(dx, dy) in &directions {
-                let new_x = start_x as isize + dx;
-                let new_y = start_y as isize + dy;
-    
-                if new_x >= 0 && new_y >= 0 {
-                    let new_x = new_x as usize;
-                    let new_y = new_y as usize;
-                    if map.is_valid(new_x, new_y) {
-                        return Some((new_x, new_y));
-                    }
-                }
-            }
-            return None;
-        }
-    
-        let mut path = Vec::new();
-        let mut current = Some((target_x, target_y));
-    
-        while let Some(pos) = current {
-            path.push(pos);
-            current = came_from.get(&pos).cloned().flatten();
-        }
-    
-        path.reverse();
-        if path.len() > 2 {
-            Some(path[1])
-        } else {
-            None
-        }
     }
-    
-    
-    
 
     fn set_position(&mut self, x: usize, y: usize);
 
@@ -0,0 +1,203 @@
+use super::battery::Battery;
+use super::robot::{Robot, RobotState, RobotType};
+use super::strategies::{BuiltinStrategy, StrategyKind};
+use crate::maps::{
+    knowledge::KnowledgeMap,
+    map::Map,
+    tile::{MapTile, Resource, ResourceType, TileType},
+};
+use crate::pathfinding::path_cache::PathCache;
+use crate::simulation::events::SimulationEvent;
+use crate::simulation::messages::Order;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_ANALYSIS_TICKS: u32 = 5;
+
+/// Robot qui analyse un site scientifique : il reste à côté du site pendant
+/// `analysis_ticks` ticks, puis rapporte les points à la base.
+/// Rappelé avant la fin, il garde sa progression et reprend l'analyse après recharge.
+/// Sans site désigné par la base, il en cherche un avec sa stratégie.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scientist {
+    id: usize,
+    x: usize,
+    y: usize,
+    analysis_ticks: u32,
+    /// Ticks déjà passés sur le site visé.
+    progress: u32,
+    state: RobotState,
+    target_resource: Option<(usize, usize, Resource, bool)>,
+    /// Résultats d'analyse à rapporter à la base.
+    cargo: Option<Resource>,
+    path: PathCache,
+    battery: Battery,
+    #[serde(default)]
+    climbing: u32,
+    strategy: BuiltinStrategy,
+    knowledge: KnowledgeMap,
+    #[serde(skip)]
+    events: Vec<SimulationEvent>,
+}
+
+impl Robot for Scientist {
+    fn new(x: usize, y: usize, id: usize) -> Self {
+        Scientist::with_analysis_ticks(x, y, id, DEFAULT_ANALYSIS_TICKS)
+    }
+
+    fn get_id(&self) -> usize {
+        self.id
+    }
+
+    fn get_type(&self) -> RobotType {
+        RobotType::Scientist
+    }
+
+    fn get_position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    fn get_state(&self) -> RobotState {
+        self.state
+    }
+
+    fn set_state(&mut self, state: RobotState) {
+        self.state = state;
+    }
+
+    fn set_position(&mut self, x: usize, y: usize) {
+        self.x = x;
+        self.y = y;
+    }
+
+    fn get_current_resource(&self) -> Option<(usize, usize, Resource, bool)> {
+        self.target_resource
+    }
+
+    fn set_target_resource(&mut self, target: Option<(usize, usize, Resource, bool)>) {
+        self.target_resource = target;
+        self.progress = 0;
+    }
+
+    fn pending_events(&mut self) -> &mut Vec<SimulationEvent> {
+        &mut self.events
+    }
+
+    fn path_cache(&mut self) -> &mut PathCache {
+        &mut self.path
+    }
+
+    fn get_battery(&self) -> Battery {
+        self.battery
+    }
+
+    fn battery(&mut self) -> &mut Battery {
+        &mut self.battery
+    }
+
+    fn climbing(&mut self) -> &mut u32 {
+        &mut self.climbing
+    }
+
+    fn strategy(&mut self) -> &mut BuiltinStrategy {
+        &mut self.strategy
+    }
+
+    fn knowledge(&mut self) -> &mut KnowledgeMap {
+        &mut self.knowledge
+    }
+
+    fn wanted_resources(&self) -> Vec<ResourceType> {
+        vec![ResourceType::Science]
+    }
+
+    fn receive(&mut self, order: Order) {
+        match order {
+            Order::HarvestRequest { position, resource } => {
+                self.set_target_resource(Some((position.0, position.1, resource, true)));
+                self.set_state(RobotState::Analyzing);
+            }
+            Order::Cancel => {
+                self.set_target_resource(None);
+                self.set_state(RobotState::ReturningToBase);
+            }
+        }
+    }
+
+    fn take_cargo(&mut self) -> Option<Resource> {
+        self.cargo.take()
+    }
+
+    fn update(&mut self, map: &mut Map) {
+        match self.state {
+            RobotState::Analyzing => {
+                if self.target_resource.is_some() {
+                    self.analyze(map);
+                } else {
+                    self.search(map);
+                }
+            }
+            RobotState::ReturningToBase => {
+                self.return_to_base(map);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Scientist {
+    pub fn with_analysis_ticks(x: usize, y: usize, id: usize, analysis_ticks: u32) -> Self {
+        Scientist {
+            id,
+            x,
+            y,
+            analysis_ticks,
+            progress: 0,
+            state: RobotState::Analyzing,
+            target_resource: None,
+            cargo: None,
+            path: PathCache::default(),
+            battery: Battery::default(),
+            climbing: 0,
+            strategy: StrategyKind::NearestResource.build(),
+            knowledge: KnowledgeMap::default(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn progress(&self) -> u32 {
+        self.progress
+    }
+
+    pub fn analyze(&mut self, map: &mut Map) {
+        let Some((x, y, _, _)) = self.target_resource else {
+            return;
+        };
+        if let Some((next_x, next_y)) = self.calculate_next_step(x, y, map) {
+            self.move_to(next_x, next_y, map);
+            return;
+        }
+
+        match map.get(x, y).tile {
+            TileType::Resource(site) if site.resource_type == ResourceType::Science => {
+                if !self.spend_action() {
+                    return;
+                }
+                self.progress += 1;
+                if self.progress < self.analysis_ticks {
+                    return;
+                }
+                self.events.push(SimulationEvent::Analyzed {
+                    robot_id: self.id,
+                    position: (x, y),
+                    points: site.scale,
+                });
+                map.set(MapTile::new(x, y, TileType::Empty));
+                self.cargo = Some(site);
+                self.target_resource = None;
+            }
+            // Site déjà analysé par un autre robot : rien à rapporter.
+            _ => self.target_resource = None,
+        }
+        self.set_state(RobotState::ReturningToBase);
+    }
+}
@@ -0,0 +1,91 @@
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+
+use crate::maps::knowledge::KnowledgeMap;
+use crate::maps::tile::ResourceType;
+
+use super::{Action, Perception, Strategy, StrategyKind};
+
+/// Se dirige vers la frontière connue la plus proche : une case vue et praticable
+/// dont une voisine n'a pas encore été vue. Rentre quand il n'en reste plus.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Frontier {
+    seen: HashSet<(usize, usize)>,
+    passable: HashSet<(usize, usize)>,
+    target: Option<(usize, usize)>,
+}
+
+impl Frontier {
+    pub fn seen(&self) -> &HashSet<(usize, usize)> {
+        &self.seen
+    }
+
+    fn mark_seen(&mut self, position: (usize, usize), standable: bool) {
+        self.seen.insert(position);
+        if standable {
+            self.passable.insert(position);
+        } else {
+            self.passable.remove(&position);
+        }
+    }
+
+    fn is_frontier(&self, position: (usize, usize), perception: &Perception) -> bool {
+        self.passable.contains(&position)
+            && perception
+                .neighbours(position)
+                .any(|neighbour| !self.seen.contains(&neighbour))
+    }
+
+    /// Parcours en largeur sur les cases vues et praticables, depuis la position du robot.
+    fn nearest_frontier(&self, perception: &Perception) -> Option<(usize, usize)> {
+        let start = perception.position;
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(position) = queue.pop_front() {
+            if position != start && self.is_frontier(position, perception) {
+                return Some(position);
+            }
+            for neighbour in perception.neighbours(position) {
+                if self.passable.contains(&neighbour) && visited.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Strategy for Frontier {
+    fn kind(&self) -> StrategyKind {
+        StrategyKind::Frontier
+    }
+
+    fn decide(&mut self, perception: &Perception, _: &mut ChaCha8Rng) -> Action {
+        for tile in &perception.visible {
+            self.mark_seen(tile.position, tile.standable);
+        }
+
+        let position = perception.position;
+        let target = match self
+            .target
+            .filter(|&target| target != position && self.is_frontier(target, perception))
+        {
+            Some(target) => target,
+            None => match self.nearest_frontier(perception) {
+                Some(target) => target,
+                None => return Action::ReturnToBase,
+            },
+        };
+        self.target = Some(target);
+        Action::MoveTo(target)
+    }
+
+    /// Les cases déjà connues de la station ne sont plus des frontières à explorer.
+    fn learn(&mut self, knowledge: &KnowledgeMap, _: &[ResourceType]) {
+        for (position, known) in knowledge.known() {
+            self.mark_seen(position, known.standable);
+        }
+    }
+}
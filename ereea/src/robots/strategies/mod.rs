@@ -0,0 +1,206 @@
+pub mod frontier;
+pub mod nearest_resource;
+pub mod random_walk;
+pub mod spiral;
+
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::maps::knowledge::KnowledgeMap;
+use crate::maps::tile::{ResourceType, TileType};
+use crate::maps::movement::{Movement, Neighbourhood};
+use crate::maps::topology::Topology;
+
+use super::robot::RobotType;
+use frontier::Frontier;
+use nearest_resource::NearestResource;
+use random_walk::RandomWalk;
+use spiral::Spiral;
+
+/// Case perçue par un robot à ce tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VisibleTile {
+    pub position: (usize, usize),
+    pub tile: TileType,
+    /// Un robot peut s'y tenir (qu'elle soit occupée ou non).
+    pub standable: bool,
+}
+
+/// Ce qu'un robot sait de son environnement au moment de choisir son action.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Perception {
+    pub position: (usize, usize),
+    pub base_position: (usize, usize),
+    pub topology: Topology,
+    pub movement: Movement,
+    pub width: usize,
+    pub height: usize,
+    /// Les cases à portée de vue du robot, la sienne d'abord.
+    pub visible: Vec<VisibleTile>,
+    /// Types de ressource que ce robot sait exploiter.
+    pub wanted: Vec<ResourceType>,
+}
+
+impl Perception {
+    pub fn tile_at(&self, position: (usize, usize)) -> Option<&VisibleTile> {
+        self.visible.iter().find(|tile| tile.position == position)
+    }
+
+    pub fn is_wanted(&self, tile: TileType) -> bool {
+        matches!(tile, TileType::Resource(resource) if self.wanted.contains(&resource.resource_type))
+    }
+
+    pub fn neighbourhood(&self) -> Neighbourhood {
+        Neighbourhood {
+            topology: self.topology,
+            movement: self.movement,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Voisines de `position`, orthogonales d'abord ; une diagonale ne coupe pas
+    /// le coin d'une case de relief visible.
+    pub fn neighbours(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbourhood().neighbours(position, |corner| {
+            self.tile_at(corner)
+                .is_some_and(|tile| tile.tile == TileType::Terrain)
+        })
+    }
+
+    /// Nombre minimal de pas entre deux cases, sans tenir compte des obstacles.
+    pub fn distance(&self, a: (usize, usize), b: (usize, usize)) -> usize {
+        self.neighbourhood().distance(a, b)
+    }
+}
+
+/// Décision d'une stratégie pour le tick en cours.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    /// Avancer d'une case vers cette position.
+    MoveTo((usize, usize)),
+    /// Agir sur la ressource de cette case : la signaler, la récolter ou l'analyser.
+    Work((usize, usize)),
+    ReturnToBase,
+    Wait,
+}
+
+/// Choisit l'action d'un robot à partir de ce qu'il perçoit.
+///
+/// Une stratégie ne décide que lorsque le robot n'a pas de cible imposée par la base ;
+/// elle peut garder sa propre mémoire d'un tick à l'autre.
+pub trait Strategy {
+    fn kind(&self) -> StrategyKind;
+    fn decide(&mut self, perception: &Perception, rng: &mut ChaCha8Rng) -> Action;
+
+    /// Intègre une carte apprise à la base. Par défaut, la stratégie l'ignore.
+    fn learn(&mut self, _knowledge: &KnowledgeMap, _wanted: &[ResourceType]) {}
+}
+
+/// Nom d'une stratégie intégrée, utilisé dans les scénarios et sur la ligne de commande.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StrategyKind {
+    RandomWalk,
+    Frontier,
+    Spiral,
+    NearestResource,
+}
+
+impl StrategyKind {
+    pub fn build(self) -> BuiltinStrategy {
+        match self {
+            StrategyKind::RandomWalk => BuiltinStrategy::RandomWalk(RandomWalk),
+            StrategyKind::Frontier => BuiltinStrategy::Frontier(Frontier::default()),
+            StrategyKind::Spiral => BuiltinStrategy::Spiral(Spiral::default()),
+            StrategyKind::NearestResource => {
+                BuiltinStrategy::NearestResource(NearestResource::default())
+            }
+        }
+    }
+
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "random-walk" => Ok(StrategyKind::RandomWalk),
+            "frontier" => Ok(StrategyKind::Frontier),
+            "spiral" => Ok(StrategyKind::Spiral),
+            "nearest-resource" => Ok(StrategyKind::NearestResource),
+            _ => Err(format!("unknown strategy '{}'", name)),
+        }
+    }
+}
+
+/// Stratégie intégrée avec son état, sérialisable avec le robot qui la porte.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BuiltinStrategy {
+    RandomWalk(RandomWalk),
+    Frontier(Frontier),
+    Spiral(Spiral),
+    NearestResource(NearestResource),
+}
+
+impl BuiltinStrategy {
+    fn inner(&mut self) -> &mut dyn Strategy {
+        match self {
+            BuiltinStrategy::RandomWalk(strategy) => strategy,
+            BuiltinStrategy::Frontier(strategy) => strategy,
+            BuiltinStrategy::Spiral(strategy) => strategy,
+            BuiltinStrategy::NearestResource(strategy) => strategy,
+        }
+    }
+}
+
+impl Strategy for BuiltinStrategy {
+    fn kind(&self) -> StrategyKind {
+        match self {
+            BuiltinStrategy::RandomWalk(_) => StrategyKind::RandomWalk,
+            BuiltinStrategy::Frontier(_) => StrategyKind::Frontier,
+            BuiltinStrategy::Spiral(_) => StrategyKind::Spiral,
+            BuiltinStrategy::NearestResource(_) => StrategyKind::NearestResource,
+        }
+    }
+
+    fn decide(&mut self, perception: &Perception, rng: &mut ChaCha8Rng) -> Action {
+        self.inner().decide(perception, rng)
+    }
+
+    fn learn(&mut self, knowledge: &KnowledgeMap, wanted: &[ResourceType]) {
+        self.inner().learn(knowledge, wanted)
+    }
+}
+
+/// Stratégie donnée à chaque type de robot lors de sa construction.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StrategyConfig {
+    pub explorer: StrategyKind,
+    pub harvester: StrategyKind,
+    pub scientist: StrategyKind,
+    /// Route d'un relais envoyé sans poste fixe.
+    pub relay: StrategyKind,
+}
+
+impl Default for StrategyConfig {
+    fn default() -> Self {
+        StrategyConfig {
+            explorer: StrategyKind::Frontier,
+            harvester: StrategyKind::NearestResource,
+            scientist: StrategyKind::NearestResource,
+            relay: StrategyKind::Spiral,
+        }
+    }
+}
+
+impl StrategyConfig {
+    pub fn get(&self, robot_type: RobotType) -> StrategyKind {
+        match robot_type {
+            RobotType::Explorer => self.explorer,
+            RobotType::Harvester => self.harvester,
+            RobotType::Scientist => self.scientist,
+            RobotType::Relay => self.relay,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::maps::knowledge::KnowledgeMap;
+use crate::maps::tile::ResourceType;
+
+use super::random_walk::RandomWalk;
+use super::{Action, Perception, Strategy, StrategyKind};
+
+/// Va vers la ressource utile la plus proche parmi celles déjà aperçues ;
+/// marche au hasard tant qu'il n'en connaît aucune.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct NearestResource {
+    known: Vec<(usize, usize)>,
+    wander: RandomWalk,
+}
+
+impl NearestResource {
+    pub fn known(&self) -> &[(usize, usize)] {
+        &self.known
+    }
+}
+
+impl Strategy for NearestResource {
+    fn kind(&self) -> StrategyKind {
+        StrategyKind::NearestResource
+    }
+
+    fn decide(&mut self, perception: &Perception, rng: &mut ChaCha8Rng) -> Action {
+        for tile in &perception.visible {
+            let wanted = perception.is_wanted(tile.tile);
+            let known = self.known.contains(&tile.position);
+            if wanted && !known {
+                self.known.push(tile.position);
+            } else if !wanted && known {
+                self.known.retain(|&position| position != tile.position);
+            }
+        }
+
+        let position = perception.position;
+        let nearest = self
+            .known
+            .iter()
+            .copied()
+            .min_by_key(|&target| (perception.distance(position, target), target));
+        match nearest {
+            Some(target) if perception.distance(position, target) == 1 => Action::Work(target),
+            Some(target) => Action::MoveTo(target),
+            None => self.wander.decide(perception, rng),
+        }
+    }
+
+    /// Reprend la liste des ressources utiles connues de la station.
+    fn learn(&mut self, knowledge: &KnowledgeMap, wanted: &[ResourceType]) {
+        self.known = knowledge
+            .resources()
+            .filter(|(_, _, resource)| wanted.contains(&resource.resource_type))
+            .map(|(x, y, _)| (x, y))
+            .collect();
+    }
+}
@@ -0,0 +1,28 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{Action, Perception, Strategy, StrategyKind};
+
+/// Un pas au hasard vers l'une des cases voisines ; agit sur la ressource touchée.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct RandomWalk;
+
+impl Strategy for RandomWalk {
+    fn kind(&self) -> StrategyKind {
+        StrategyKind::RandomWalk
+    }
+
+    fn decide(&mut self, perception: &Perception, rng: &mut ChaCha8Rng) -> Action {
+        let neighbours: Vec<_> = perception.neighbours(perception.position).collect();
+        if neighbours.is_empty() {
+            return Action::Wait;
+        }
+        let (new_x, new_y) = neighbours[rng.random_range(0..neighbours.len())];
+        match perception.tile_at((new_x, new_y)) {
+            Some(tile) if perception.is_wanted(tile.tile) => Action::Work((new_x, new_y)),
+            Some(tile) if tile.standable => Action::MoveTo((new_x, new_y)),
+            _ => Action::Wait,
+        }
+    }
+}
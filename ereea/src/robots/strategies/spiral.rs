@@ -0,0 +1,85 @@
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{Action, Perception, Strategy, StrategyKind};
+
+/// Écart entre deux points de passage : avec une vue d'une case, rien n'est manqué.
+const SPACING: usize = 3;
+
+/// Parcourt des anneaux carrés de plus en plus grands autour du point de départ.
+/// Un point de passage hors carte, infranchissable ou trop long à atteindre est sauté.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Spiral {
+    anchor: Option<(usize, usize)>,
+    ring: usize,
+    index: usize,
+    /// Ticks passés à viser le point de passage courant.
+    patience: usize,
+}
+
+impl Spiral {
+    /// `index`-ième point de l'anneau `ring`, en partant du bord droit.
+    fn ring_offset(ring: usize, index: usize) -> (isize, isize) {
+        let r = ring as isize;
+        let side = index / (2 * ring);
+        let offset = (index % (2 * ring)) as isize;
+        match side {
+            0 => (r, -r + 1 + offset),
+            1 => (r - 1 - offset, r),
+            2 => (-r, r - 1 - offset),
+            _ => (-r + 1 + offset, -r),
+        }
+    }
+
+    fn advance(&mut self) {
+        self.patience = 0;
+        self.index += 1;
+        if self.index >= 8 * self.ring {
+            self.ring += 1;
+            self.index = 0;
+        }
+    }
+
+    /// Point de passage courant, ou `None` s'il sort de la carte.
+    fn waypoint(&self, anchor: (usize, usize), perception: &Perception) -> Option<(usize, usize)> {
+        let (dx, dy) = Self::ring_offset(self.ring, self.index);
+        let x = anchor.0.checked_add_signed(dx * SPACING as isize)?;
+        let y = anchor.1.checked_add_signed(dy * SPACING as isize)?;
+        (x < perception.width && y < perception.height).then_some((x, y))
+    }
+}
+
+impl Strategy for Spiral {
+    fn kind(&self) -> StrategyKind {
+        StrategyKind::Spiral
+    }
+
+    fn decide(&mut self, perception: &Perception, _: &mut ChaCha8Rng) -> Action {
+        let anchor = *self.anchor.get_or_insert(perception.position);
+        if self.ring == 0 {
+            self.ring = 1;
+        }
+        let max_ring = perception.width.max(perception.height) / SPACING + 1;
+
+        while self.ring <= max_ring {
+            match self.waypoint(anchor, perception) {
+                Some(waypoint) => {
+                    let (x, y) = perception.position;
+                    let reached = x.abs_diff(waypoint.0) <= 1 && y.abs_diff(waypoint.1) <= 1;
+                    let blocked = perception
+                        .tile_at(waypoint)
+                        .is_some_and(|tile| !tile.standable);
+                    let too_long = self.patience > 4 * SPACING * self.ring;
+                    if reached || blocked || too_long {
+                        self.advance();
+                        continue;
+                    }
+                    self.patience += 1;
+                    return Action::MoveTo(waypoint);
+                }
+                None => self.advance(),
+            }
+        }
+        Action::ReturnToBase
+    }
+}
@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::maps::knowledge::KnowledgeMap;
+use crate::maps::tile::{Resource, ResourceType, TileType};
+use crate::robots::robot::RobotType;
+
+use super::radio::distance;
+
+/// Poids utilisés pour classer les sites en attente ; le meilleur score est servi d'abord.
+/// score = priorité du type + `quantity_weight` × quantité - `distance_weight` × distance à la base
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DispatchConfig {
+    pub distance_weight: f64,
+    pub quantity_weight: f64,
+    pub energy_priority: f64,
+    pub mineral_priority: f64,
+    pub science_priority: f64,
+}
+
+impl Default for DispatchConfig {
+    fn default() -> Self {
+        DispatchConfig {
+            distance_weight: 1.0,
+            quantity_weight: 0.5,
+            energy_priority: 10.0,
+            mineral_priority: 10.0,
+            science_priority: 20.0,
+        }
+    }
+}
+
+impl DispatchConfig {
+    pub fn priority(&self, resource_type: ResourceType) -> f64 {
+        match resource_type {
+            ResourceType::Energy => self.energy_priority,
+            ResourceType::Mineral => self.mineral_priority,
+            ResourceType::Science => self.science_priority,
+        }
+    }
+}
+
+/// Site connu de la base, en attente d'un robot ou en cours d'exploitation.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Site {
+    pub position: (usize, usize),
+    pub resource: Resource,
+}
+
+/// Type de robot qui exploite une ressource.
+pub fn worker_type(resource_type: ResourceType) -> RobotType {
+    match resource_type {
+        ResourceType::Science => RobotType::Scientist,
+        _ => RobotType::Harvester,
+    }
+}
+
+/// Répartiteur de la base : file des sites connus, travaux en cours
+/// et robots garés à la base en attente d'un ordre.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dispatcher {
+    pub config: DispatchConfig,
+    /// Sites sans robot, dans l'ordre où ils ont été signalés.
+    queue: Vec<Site>,
+    /// Toutes les cases déjà signalées, pour ne pas les ajouter deux fois.
+    known: HashSet<(usize, usize)>,
+    /// Site de chaque robot au travail, par identifiant.
+    jobs: BTreeMap<usize, Site>,
+    /// Robots garés à la base, par ordre d'arrivée.
+    idle: Vec<(usize, RobotType)>,
+}
+
+impl Default for Dispatcher {
+    fn default() -> Self {
+        Dispatcher::new(DispatchConfig::default())
+    }
+}
+
+impl Dispatcher {
+    pub fn new(config: DispatchConfig) -> Self {
+        Dispatcher {
+            config,
+            queue: Vec::new(),
+            known: HashSet::new(),
+            jobs: BTreeMap::new(),
+            idle: Vec::new(),
+        }
+    }
+
+    /// Ajoute un site à la file ; `false` s'il était déjà connu.
+    pub fn add_site(&mut self, position: (usize, usize), resource: Resource) -> bool {
+        if !self.known.insert(position) {
+            return false;
+        }
+        self.queue.push(Site { position, resource });
+        true
+    }
+
+    pub fn is_known(&self, position: (usize, usize)) -> bool {
+        self.known.contains(&position)
+    }
+
+    pub fn queue(&self) -> &[Site] {
+        &self.queue
+    }
+
+    pub fn jobs(&self) -> &BTreeMap<usize, Site> {
+        &self.jobs
+    }
+
+    pub fn idle(&self) -> &[(usize, RobotType)] {
+        &self.idle
+    }
+
+    fn score(&self, site: &Site, base: (usize, usize)) -> f64 {
+        self.config.priority(site.resource.resource_type)
+            + self.config.quantity_weight * site.resource.scale as f64
+            - self.config.distance_weight * distance(base, site.position) as f64
+    }
+
+    /// Sites en attente du meilleur au moins bon score ; à score égal, le plus ancien d'abord.
+    pub fn ranked_sites(&self, base: (usize, usize)) -> Vec<Site> {
+        let mut sites = self.queue.clone();
+        sites.sort_by(|a, b| self.score(b, base).total_cmp(&self.score(a, base)));
+        sites
+    }
+
+    /// Confie le site à un robot et le retire de la file.
+    pub fn assign(&mut self, robot_id: usize, site: Site) {
+        self.queue.retain(|queued| queued.position != site.position);
+        self.jobs.insert(robot_id, site);
+    }
+
+    /// Gare un robot à la base ; son éventuel travail est terminé.
+    pub fn park(&mut self, robot_id: usize, robot_type: RobotType) {
+        self.jobs.remove(&robot_id);
+        if !self.idle.iter().any(|&(id, _)| id == robot_id) {
+            self.idle.push((robot_id, robot_type));
+        }
+    }
+
+    /// Premier robot garé du type voulu, retiré du pool.
+    pub fn take_idle(&mut self, robot_type: RobotType) -> Option<usize> {
+        let index = self.idle.iter().position(|&(_, kind)| kind == robot_type)?;
+        Some(self.idle.remove(index).0)
+    }
+
+    /// Robot perdu : son site retourne dans la file.
+    pub fn abandon(&mut self, robot_id: usize) {
+        self.idle.retain(|&(id, _)| id != robot_id);
+        if let Some(site) = self.jobs.remove(&robot_id) {
+            self.queue.push(site);
+        }
+    }
+
+    /// Retire les sites que la station sait vidés, en attente ou en cours.
+    /// Retourne les robots dont le travail est annulé.
+    pub fn cancel_depleted(&mut self, station: &KnowledgeMap) -> Vec<usize> {
+        let depleted = |site: &Site| {
+            let (x, y) = site.position;
+            station
+                .get(x, y)
+                .is_some_and(|known| !matches!(known.tile, TileType::Resource(_)))
+        };
+        self.queue.retain(|site| !depleted(site));
+        let cancelled: Vec<usize> = self
+            .jobs
+            .iter()
+            .filter(|(_, site)| depleted(site))
+            .map(|(&robot_id, _)| robot_id)
+            .collect();
+        for robot_id in &cancelled {
+            self.jobs.remove(robot_id);
+        }
+        cancelled
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use crate::maps::tile::ResourceType;
+use crate::robots::robot::RobotType;
+
+/// Quantités d'énergie et de minerais, pour un prix ou un stock de départ.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Cost {
+    pub energy: u32,
+    pub minerals: u32,
+}
+
+impl Cost {
+    pub fn new(energy: u32, minerals: u32) -> Self {
+        Cost { energy, minerals }
+    }
+
+    pub fn amounts(&self) -> [(ResourceType, u32); 2] {
+        [
+            (ResourceType::Energy, self.energy),
+            (ResourceType::Mineral, self.minerals),
+        ]
+    }
+}
+
+/// Prix de construction de chaque type de robot, prélevé sur le stock de la base.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostTable {
+    pub explorer: Cost,
+    pub harvester: Cost,
+    pub scientist: Cost,
+    pub relay: Cost,
+}
+
+impl Default for CostTable {
+    fn default() -> Self {
+        CostTable {
+            explorer: Cost::new(5, 0),
+            harvester: Cost::new(5, 5),
+            scientist: Cost::new(10, 5),
+            relay: Cost::new(10, 10),
+        }
+    }
+}
+
+impl CostTable {
+    /// Table où tous les robots sont gratuits.
+    pub fn free() -> Self {
+        CostTable {
+            explorer: Cost::default(),
+            harvester: Cost::default(),
+            scientist: Cost::default(),
+            relay: Cost::default(),
+        }
+    }
+
+    pub fn cost(&self, robot_type: RobotType) -> Cost {
+        match robot_type {
+            RobotType::Explorer => self.explorer,
+            RobotType::Harvester => self.harvester,
+            RobotType::Scientist => self.scientist,
+            RobotType::Relay => self.relay,
+        }
+    }
+}
+
+/// Économie de la base : stock de départ et prix des robots.
+/// La flotte du scénario est déployée gratuitement au lancement.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EconomyConfig {
+    pub starting_funds: Cost,
+    pub costs: CostTable,
+}
+
+impl Default for EconomyConfig {
+    fn default() -> Self {
+        EconomyConfig {
+            starting_funds: Cost::new(50, 20),
+            costs: CostTable::default(),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::maps::map::Map;
+use crate::maps::tile::{Resource, ResourceType};
+use crate::robots::robot::RobotType;
+
+/// Changement d'état observable de la simulation.
+///
+/// Les événements qui modifient la carte portent assez d'information pour
+/// que `Replay` puisse rejouer la modification sans relancer les robots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SimulationEvent {
+    RobotSpawned {
+        robot_id: usize,
+        robot_type: RobotType,
+        position: (usize, usize),
+    },
+    RobotMoved {
+        robot_id: usize,
+        robot_type: RobotType,
+        from: (usize, usize),
+        to: (usize, usize),
+    },
+    ResourceDiscovered {
+        robot_id: usize,
+        position: (usize, usize),
+        resource: Resource,
+    },
+    Harvested {
+        robot_id: usize,
+        position: (usize, usize),
+        resource_type: ResourceType,
+        amount: u32,
+        /// Quantité laissée sur la case ; 0 si elle a été vidée.
+        remaining: u32,
+    },
+    /// Un site scientifique a été analysé et retiré de la carte.
+    Analyzed {
+        robot_id: usize,
+        position: (usize, usize),
+        points: u32,
+    },
+    ReturnedToBase {
+        robot_id: usize,
+    },
+    /// Ressources signalées par radio, sans retour à la base.
+    ReportTransmitted {
+        robot_id: usize,
+        position: (usize, usize),
+        resources: usize,
+    },
+    ResourceCredited {
+        resource_type: ResourceType,
+        amount: u32,
+    },
+    RobotRetired {
+        robot_id: usize,
+        position: (usize, usize),
+    },
+    /// Incident dû au biome de la case : le robot perd une partie de sa charge.
+    HazardStruck {
+        robot_id: usize,
+        position: (usize, usize),
+        drain: u32,
+    },
+    /// Batterie vide loin de la base : le robot reste immobile sur sa case.
+    RobotLost {
+        robot_id: usize,
+        position: (usize, usize),
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    pub tick: u64,
+    pub event: SimulationEvent,
+}
+
+/// Journal ordonné des événements, avec la carte de départ nécessaire au rejeu.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventLog {
+    pub start_tick: u64,
+    pub initial_map: Map,
+    pub events: Vec<RecordedEvent>,
+}
+
+impl EventLog {
+    pub fn new(initial_map: Map, start_tick: u64) -> Self {
+        EventLog {
+            start_tick,
+            initial_map,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, tick: u64, event: SimulationEvent) {
+        self.events.push(RecordedEvent { tick, event });
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let content = serde_json::to_string(self).map_err(|error| error.to_string())?;
+        std::fs::write(path, content)
+            .map_err(|error| format!("cannot write {}: {}", path.display(), error))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+        serde_json::from_str(&content).map_err(|error| error.to_string())
+    }
+}
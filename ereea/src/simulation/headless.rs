@@ -0,0 +1,207 @@
+use serde::Serialize;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use crate::maps::movement::Movement;
+use crate::maps::tile::ResourceType;
+use crate::maps::topology::Topology;
+use crate::robots::strategies::StrategyKind;
+
+use super::scenario::Scenario;
+use super::simulation::Simulation;
+
+const USAGE: &str = "Usage: ereea --headless [--scenario FILE] [--seed N] [--width N] [--height N] [--topology bounded|torus|sphere] [--movement four-way|eight-way] \
+[--explorers N] [--harvesters N] [--exploration random-walk|frontier|spiral|nearest-resource] [--ticks N] [--time-limit SECONDS] [--format text|json] \
+[--load SNAPSHOT] [--save SNAPSHOT] [--events LOG]";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+/// Paramètres d'une exécution sans interface graphique.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadlessOptions {
+    pub scenario: Scenario,
+    pub format: OutputFormat,
+    /// Instantané à reprendre à la place d'une nouvelle simulation.
+    pub load: Option<PathBuf>,
+    /// Fichier où enregistrer l'instantané final.
+    pub save: Option<PathBuf>,
+    /// Fichier où enregistrer le journal d'événements.
+    pub events: Option<PathBuf>,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        let mut scenario = Scenario::default();
+        scenario.fleet.explorers = 4;
+        HeadlessOptions {
+            scenario,
+            format: OutputFormat::Text,
+            load: None,
+            save: None,
+            events: None,
+        }
+    }
+}
+
+impl HeadlessOptions {
+    /// Lit les options de la ligne de commande (sans le nom du programme).
+    /// `--headless` est accepté et ignoré pour pouvoir passer `std::env::args` tel quel.
+    /// Les options explicites l'emportent sur celles du fichier `--scenario`.
+    pub fn from_args<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut pairs = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--headless" {
+                continue;
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {}\n{}", arg, USAGE))?;
+            pairs.push((arg, value));
+        }
+
+        let mut options = match pairs.iter().find(|(arg, _)| arg == "--scenario") {
+            Some((_, path)) => HeadlessOptions {
+                scenario: Scenario::load(path)?,
+                ..HeadlessOptions::default()
+            },
+            None => HeadlessOptions::default(),
+        };
+
+        let scenario = &mut options.scenario;
+        for (arg, value) in &pairs {
+            match arg.as_str() {
+                "--scenario" => {}
+                "--load" => options.load = Some(PathBuf::from(value)),
+                "--save" => options.save = Some(PathBuf::from(value)),
+                "--events" => options.events = Some(PathBuf::from(value)),
+                "--seed" => scenario.seed = parse_value(arg, value)?,
+                "--width" => scenario.map.width = parse_value(arg, value)?,
+                "--height" => scenario.map.height = parse_value(arg, value)?,
+                "--topology" => {
+                    scenario.map.topology = Topology::parse(value)
+                        .map_err(|error| format!("{}\n{}", error, USAGE))?
+                }
+                "--movement" => {
+                    scenario.map.movement = Movement::parse(value)
+                        .map_err(|error| format!("{}\n{}", error, USAGE))?
+                }
+                "--explorers" => scenario.fleet.explorers = parse_value(arg, value)?,
+                "--harvesters" => scenario.fleet.harvesters = parse_value(arg, value)?,
+                "--exploration" => {
+                    scenario.strategies.explorer = StrategyKind::parse(value)
+                        .map_err(|error| format!("{}\n{}", error, USAGE))?
+                }
+                "--ticks" => scenario.stop.max_ticks = Some(parse_value(arg, value)?),
+                "--time-limit" => scenario.stop.time_limit = Some(parse_value(arg, value)?),
+                "--format" => {
+                    options.format = match value.as_str() {
+                        "text" => OutputFormat::Text,
+                        "json" => OutputFormat::Json,
+                        _ => return Err(format!("unknown format '{}'\n{}", value, USAGE)),
+                    }
+                }
+                _ => return Err(format!("unknown option '{}'\n{}", arg, USAGE)),
+            }
+        }
+
+        options.scenario.validate()?;
+        if !options.scenario.stop.has_budget() {
+            options.scenario.stop.max_ticks = Some(1000);
+        }
+        Ok(options)
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(option: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for {}", value, option))
+}
+
+/// Bilan d'une exécution, affiché à la fin du mode headless.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimulationSummary {
+    pub seed: u32,
+    pub width: usize,
+    pub height: usize,
+    pub ticks: u64,
+    pub energy_collected: u32,
+    pub minerals_collected: u32,
+    pub science_collected: u32,
+    pub tiles_explored: usize,
+    /// Pourcentage des cases connues de la station.
+    pub coverage: f64,
+    pub robots_lost: u32,
+}
+
+impl SimulationSummary {
+    pub fn from_simulation(simulation: &Simulation) -> Self {
+        let map = simulation.map.read().unwrap();
+        SimulationSummary {
+            seed: map.seed,
+            width: map.width,
+            height: map.height,
+            ticks: simulation.tick,
+            energy_collected: simulation.inventory.get(ResourceType::Energy),
+            minerals_collected: simulation.inventory.get(ResourceType::Mineral),
+            science_collected: simulation.inventory.get(ResourceType::Science),
+            tiles_explored: simulation.explored_tiles.len(),
+            coverage: simulation.coverage(),
+            robots_lost: simulation.robots_lost,
+        }
+    }
+
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Json => serde_json::to_string_pretty(self).unwrap(),
+            OutputFormat::Text => format!(
+                "seed: {}\nmap: {}x{}\nticks: {}\nenergy collected: {}\nminerals collected: {}\nscience collected: {}\ntiles explored: {}\ncoverage: {:.1}%\nrobots lost: {}",
+                self.seed,
+                self.width,
+                self.height,
+                self.ticks,
+                self.energy_collected,
+                self.minerals_collected,
+                self.science_collected,
+                self.tiles_explored,
+                self.coverage,
+                self.robots_lost
+            ),
+        }
+    }
+}
+
+/// Joue la simulation sans fenêtre jusqu'à ce qu'une condition d'arrêt du scénario soit atteinte.
+/// Un instantané repris avec `--load` garde son état mais suit les conditions d'arrêt des options.
+pub fn run_headless(options: &HeadlessOptions) -> Result<SimulationSummary, String> {
+    let mut simulation = match &options.load {
+        Some(path) => {
+            let mut simulation = Simulation::load(path)?;
+            simulation.stop_conditions = options.scenario.stop.clone();
+            simulation
+        }
+        None => Simulation::from_scenario(&options.scenario),
+    };
+
+    let start = Instant::now();
+    while !simulation.should_stop(start.elapsed()) {
+        simulation.step();
+    }
+
+    if let Some(path) = &options.save {
+        simulation.save(path)?;
+    }
+    if let Some(path) = &options.events {
+        simulation.events.save(path)?;
+    }
+
+    Ok(SimulationSummary::from_simulation(&simulation))
+}
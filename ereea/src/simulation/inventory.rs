@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::maps::tile::ResourceType;
+
+use super::economy::Cost;
+
+/// Dépôt à la base, avec le tick et le total atteint juste après.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InventoryRecord {
+    pub tick: u64,
+    pub resource_type: ResourceType,
+    pub amount: u32,
+    pub total: u32,
+}
+
+/// Stock de la base : un compteur par type de ressource et l'historique des dépôts.
+///
+/// `get` compte tout ce qui a été rapporté ; `stock` est ce qui reste disponible
+/// après les dépenses, stock de départ compris.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BaseInventory {
+    totals: BTreeMap<ResourceType, u32>,
+    stock: BTreeMap<ResourceType, u32>,
+    history: Vec<InventoryRecord>,
+}
+
+impl BaseInventory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_funds(funds: Cost) -> Self {
+        let mut inventory = Self::new();
+        for (resource_type, amount) in funds.amounts() {
+            inventory.stock.insert(resource_type, amount);
+        }
+        inventory
+    }
+
+    pub fn stock(&self, resource_type: ResourceType) -> u32 {
+        self.stock.get(&resource_type).copied().unwrap_or(0)
+    }
+
+    pub fn can_afford(&self, cost: Cost) -> bool {
+        cost.amounts()
+            .into_iter()
+            .all(|(resource_type, amount)| self.stock(resource_type) >= amount)
+    }
+
+    /// Prélève `cost` sur le stock, ou ne touche à rien si les fonds manquent.
+    pub fn spend(&mut self, cost: Cost) -> Result<(), String> {
+        if !self.can_afford(cost) {
+            return Err(format!(
+                "not enough resources: needs {} energy and {} minerals, base has {} and {}",
+                cost.energy,
+                cost.minerals,
+                self.stock(ResourceType::Energy),
+                self.stock(ResourceType::Mineral)
+            ));
+        }
+        for (resource_type, amount) in cost.amounts() {
+            *self.stock.entry(resource_type).or_insert(0) -= amount;
+        }
+        Ok(())
+    }
+
+    /// Ajoute `amount` au stock et retourne le nouveau total pour ce type.
+    pub fn credit(&mut self, tick: u64, resource_type: ResourceType, amount: u32) -> u32 {
+        *self.stock.entry(resource_type).or_insert(0) += amount;
+        let total = self.totals.entry(resource_type).or_insert(0);
+        *total += amount;
+        self.history.push(InventoryRecord {
+            tick,
+            resource_type,
+            amount,
+            total: *total,
+        });
+        *total
+    }
+
+    pub fn get(&self, resource_type: ResourceType) -> u32 {
+        self.totals.get(&resource_type).copied().unwrap_or(0)
+    }
+
+    /// Total de chaque type de ressource, y compris ceux jamais rapportés.
+    pub fn totals(&self) -> impl Iterator<Item = (ResourceType, u32)> + '_ {
+        ResourceType::ALL
+            .into_iter()
+            .map(|resource_type| (resource_type, self.get(resource_type)))
+    }
+
+    pub fn history(&self) -> &[InventoryRecord] {
+        &self.history
+    }
+
+    /// Total d'un type de ressource à la fin du tick `tick`.
+    pub fn get_at(&self, resource_type: ResourceType, tick: u64) -> u32 {
+        self.history
+            .iter()
+            .take_while(|record| record.tick <= tick)
+            .filter(|record| record.resource_type == resource_type)
+            .last()
+            .map(|record| record.total)
+            .unwrap_or(0)
+    }
+}
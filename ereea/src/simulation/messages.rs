@@ -0,0 +1,70 @@
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use crate::maps::tile::Resource;
+
+/// Message envoyé à la base par un robot, à son retour ou par radio.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Report {
+    ResourceFound {
+        robot_id: usize,
+        position: (usize, usize),
+        resource: Resource,
+    },
+    CargoDelivered {
+        robot_id: usize,
+        resource: Resource,
+    },
+    /// Batterie vide loin de la base.
+    RobotLost {
+        robot_id: usize,
+        position: (usize, usize),
+    },
+}
+
+/// Ordre de la base à un robot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Order {
+    /// Aller exploiter la ressource de cette case, puis rapporter ce qui a été récolté.
+    HarvestRequest {
+        position: (usize, usize),
+        resource: Resource,
+    },
+    /// Abandonner le site visé, vidé entre-temps, et rentrer à la base.
+    Cancel,
+}
+
+/// Canal des rapports vers le contrôleur de la base.
+/// Chaque émetteur (robot, relais, thread) reçoit sa copie de l'envoyeur ;
+/// la base lit les rapports arrivés une fois par tick.
+#[derive(Debug)]
+pub struct MessageBus {
+    sender: Sender<Report>,
+    receiver: Receiver<Report>,
+}
+
+impl Default for MessageBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageBus {
+    pub fn new() -> Self {
+        let (sender, receiver) = channel();
+        MessageBus { sender, receiver }
+    }
+
+    pub fn sender(&self) -> Sender<Report> {
+        self.sender.clone()
+    }
+
+    pub fn send(&self, report: Report) {
+        // Le bus garde son propre récepteur : l'envoi ne peut pas échouer.
+        let _ = self.sender.send(report);
+    }
+
+    /// Rapports arrivés depuis la dernière lecture, dans leur ordre d'envoi.
+    pub fn receive(&self) -> Vec<Report> {
+        self.receiver.try_iter().collect()
+    }
+}
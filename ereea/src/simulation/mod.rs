@@ -1 +1,12 @@
-pub mod simulation;
\ No newline at end of file
+pub mod dispatcher;
+pub mod economy;
+pub mod events;
+pub mod headless;
+pub mod inventory;
+pub mod messages;
+pub mod radio;
+pub mod replay;
+pub mod scenario;
+#[allow(clippy::module_inception)]
+pub mod simulation;
+pub mod snapshot;
@@ -0,0 +1,54 @@
+/// Portée radio par défaut, en cases (distance de Manhattan).
+pub const DEFAULT_RADIO_RANGE: usize = 6;
+
+/// Distance de Manhattan entre deux cases.
+pub fn distance(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+/// Émetteurs reliés à la base : la base elle-même, puis chaque relais à portée
+/// de la base ou d'un relais déjà relié.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RadioNetwork {
+    pub range: usize,
+    nodes: Vec<(usize, usize)>,
+}
+
+impl RadioNetwork {
+    pub fn build(base: (usize, usize), relays: &[(usize, usize)], range: usize) -> Self {
+        let mut nodes = vec![base];
+        let mut pending: Vec<(usize, usize)> = relays.to_vec();
+        let mut next = 0;
+        while next < nodes.len() {
+            let node = nodes[next];
+            let (linked, rest): (Vec<_>, Vec<_>) = pending
+                .into_iter()
+                .partition(|&relay| distance(node, relay) <= range);
+            nodes.extend(linked);
+            pending = rest;
+            next += 1;
+        }
+        RadioNetwork { range, nodes }
+    }
+
+    /// La base puis les relais reliés, dans l'ordre où ils ont été atteints.
+    pub fn nodes(&self) -> &[(usize, usize)] {
+        &self.nodes
+    }
+
+    /// Un robot en `position` peut joindre la base.
+    pub fn reaches(&self, position: (usize, usize)) -> bool {
+        self.nodes
+            .iter()
+            .any(|&node| distance(node, position) <= self.range)
+    }
+
+    /// Émetteur relié le plus proche de `position`.
+    pub fn nearest_node(&self, position: (usize, usize)) -> (usize, usize) {
+        *self
+            .nodes
+            .iter()
+            .min_by_key(|&&node| distance(node, position))
+            .unwrap()
+    }
+}
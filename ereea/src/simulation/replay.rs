@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+
+use crate::maps::map::Map;
+use crate::maps::tile::{MapTile, Resource, TileType};
+use crate::robots::robot::RobotType;
+
+use super::events::{EventLog, SimulationEvent};
+use super::inventory::BaseInventory;
+
+/// État reconstruit à partir d'un journal d'événements.
+/// L'inventaire ne couvre que ce qui a été crédité depuis le début du journal.
+#[derive(Debug, Clone)]
+pub struct ReplayState {
+    pub tick: u64,
+    pub map: Map,
+    pub robots: BTreeMap<usize, (RobotType, (usize, usize))>,
+    pub inventory: BaseInventory,
+}
+
+impl ReplayState {
+    fn apply(&mut self, tick: u64, event: &SimulationEvent) {
+        match *event {
+            SimulationEvent::RobotSpawned {
+                robot_id,
+                robot_type,
+                position,
+            } => {
+                self.map.place_robot(robot_id, robot_type, position);
+                self.robots.insert(robot_id, (robot_type, position));
+            }
+            SimulationEvent::RobotMoved {
+                robot_id,
+                robot_type,
+                from,
+                to,
+            } => {
+                self.map.move_robot(robot_id, robot_type, from, to);
+                self.robots.insert(robot_id, (robot_type, to));
+            }
+            SimulationEvent::Harvested {
+                position,
+                resource_type,
+                remaining,
+                ..
+            } => {
+                let tile = if remaining > 0 {
+                    TileType::Resource(Resource::new(remaining, resource_type))
+                } else {
+                    TileType::Empty
+                };
+                self.map.set(MapTile::new(position.0, position.1, tile));
+            }
+            SimulationEvent::ResourceCredited {
+                resource_type,
+                amount,
+            } => {
+                self.inventory.credit(tick, resource_type, amount);
+            }
+            SimulationEvent::Analyzed { position, .. } => {
+                self.map
+                    .set(MapTile::new(position.0, position.1, TileType::Empty));
+            }
+            SimulationEvent::RobotRetired { robot_id, position } => {
+                self.map.remove_robot(robot_id, position);
+                self.robots.remove(&robot_id);
+            }
+            SimulationEvent::ResourceDiscovered { .. }
+            | SimulationEvent::ReturnedToBase { .. }
+            | SimulationEvent::ReportTransmitted { .. }
+            | SimulationEvent::HazardStruck { .. }
+            | SimulationEvent::RobotLost { .. } => {}
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut content = String::new();
+        for y in 0..self.map.height {
+            for x in 0..self.map.width {
+                content.push(self.map.visible_tile(x, y).char());
+            }
+            content.push('\n');
+        }
+        content
+    }
+}
+
+/// Rejoue un journal pour retrouver l'état de la carte à n'importe quel tick.
+pub struct Replay {
+    log: EventLog,
+}
+
+impl Replay {
+    pub fn new(log: EventLog) -> Self {
+        Replay { log }
+    }
+
+    pub fn last_tick(&self) -> u64 {
+        self.log
+            .events
+            .last()
+            .map(|recorded| recorded.tick)
+            .unwrap_or(self.log.start_tick)
+    }
+
+    /// État après application de tous les événements jusqu'au tick `tick` inclus.
+    pub fn state_at(&self, tick: u64) -> ReplayState {
+        let mut state = ReplayState {
+            tick: tick.max(self.log.start_tick),
+            map: self.log.initial_map.clone(),
+            robots: BTreeMap::new(),
+            inventory: BaseInventory::new(),
+        };
+        for recorded in self
+            .log
+            .events
+            .iter()
+            .take_while(|recorded| recorded.tick <= tick)
+        {
+            state.apply(recorded.tick, &recorded.event);
+        }
+        state
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::maps::config::MapConfig;
+use crate::maps::knowledge::DEFAULT_FRESHNESS_TICKS;
+use crate::robots::battery::BatteryConfig;
+use crate::robots::explorer::ExplorerConfig;
+use crate::robots::harvester::DEFAULT_CARGO_CAPACITY;
+use crate::robots::scientist::DEFAULT_ANALYSIS_TICKS;
+use crate::robots::strategies::StrategyConfig;
+
+use super::dispatcher::DispatchConfig;
+use super::economy::EconomyConfig;
+use super::radio::DEFAULT_RADIO_RANGE;
+
+/// Robots déployés gratuitement depuis la base au lancement de la simulation.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FleetConfig {
+    pub explorers: usize,
+    pub harvesters: usize,
+}
+
+/// Conditions d'arrêt ; la simulation s'arrête dès que l'une d'elles est atteinte.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StopConditions {
+    pub max_ticks: Option<u64>,
+    /// Durée maximale en secondes (temps réel).
+    pub time_limit: Option<f64>,
+    pub energy_target: Option<u32>,
+    pub minerals_target: Option<u32>,
+}
+
+impl StopConditions {
+    pub fn time_limit(&self) -> Option<Duration> {
+        self.time_limit.map(Duration::from_secs_f64)
+    }
+
+    pub fn has_budget(&self) -> bool {
+        self.max_ticks.is_some() || self.time_limit.is_some()
+    }
+}
+
+/// Description complète d'un scénario, chargée depuis un fichier TOML ou JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Scenario {
+    pub seed: u32,
+    pub map: MapConfig,
+    pub fleet: FleetConfig,
+    /// Délai entre deux ticks en millisecondes dans l'interface.
+    pub speed: u64,
+    pub auto_explore: bool,
+    pub explorer: ExplorerConfig,
+    /// Stratégie de chaque type de robot.
+    pub strategies: StrategyConfig,
+    /// Âge maximal d'une observation de la station encore considérée comme fraîche.
+    pub freshness_ticks: u64,
+    /// Portée radio de la base et des relais, en cases.
+    pub radio_range: usize,
+    pub harvester_cargo_capacity: u32,
+    /// Nombre de ticks qu'un scientifique passe sur un site avant de rapporter.
+    pub scientist_analysis_ticks: u32,
+    pub economy: EconomyConfig,
+    /// Classement des sites par le répartiteur de la base.
+    pub dispatch: DispatchConfig,
+    pub battery: BatteryConfig,
+    pub stop: StopConditions,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Scenario {
+            seed: 4,
+            map: MapConfig::default(),
+            fleet: FleetConfig::default(),
+            speed: 500,
+            auto_explore: false,
+            explorer: ExplorerConfig::default(),
+            strategies: StrategyConfig::default(),
+            freshness_ticks: DEFAULT_FRESHNESS_TICKS,
+            radio_range: DEFAULT_RADIO_RANGE,
+            harvester_cargo_capacity: DEFAULT_CARGO_CAPACITY,
+            scientist_analysis_ticks: DEFAULT_ANALYSIS_TICKS,
+            economy: EconomyConfig::default(),
+            dispatch: DispatchConfig::default(),
+            battery: BatteryConfig::default(),
+            stop: StopConditions::default(),
+        }
+    }
+}
+
+impl Scenario {
+    /// Charge un scénario ; le format est choisi d'après l'extension (`.toml` ou `.json`).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+
+        let scenario: Scenario = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&content).map_err(|error| error.to_string())?,
+            Some("json") => serde_json::from_str(&content).map_err(|error| error.to_string())?,
+            _ => {
+                return Err(format!(
+                    "unsupported scenario format for {} (expected .toml or .json)",
+                    path.display()
+                ));
+            }
+        };
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        self.map.validate()?;
+        if self.harvester_cargo_capacity == 0 {
+            return Err(String::from("harvester cargo capacity must be positive"));
+        }
+        if self.battery.capacity == 0 {
+            return Err(String::from("battery capacity must be positive"));
+        }
+        if self.scientist_analysis_ticks == 0 {
+            return Err(String::from("scientist analysis ticks must be positive"));
+        }
+        Ok(())
+    }
+}
@@ -1,61 +1,252 @@
+use crate::maps::config::MapConfig;
+use crate::maps::knowledge::{KnowledgeMap, Visibility, DEFAULT_FRESHNESS_TICKS};
 use crate::maps::map::Map;
-use crate::maps::tile::{MapTile, Resource, TileType};
+use crate::maps::tile::{Resource, ResourceType};
 use crate::robots::robot::{RobotState, RobotType};
-use crate::robots::{explorer::Explorer, harvester::Harvester, robot::Robot};
+use crate::robots::{explorer::Explorer, harvester::Harvester, robot::Robot, scientist::Scientist};
+use crate::robots::relay::Relay;
+use crate::robots::battery::{Battery, BatteryConfig};
+use crate::robots::explorer::ExplorerConfig;
+use crate::robots::harvester::DEFAULT_CARGO_CAPACITY;
+use crate::robots::scientist::DEFAULT_ANALYSIS_TICKS;
+use crate::robots::strategies::{StrategyConfig, StrategyKind};
 use crate::UI::utils::open_window;
-use std::collections::{HashMap, VecDeque};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex, RwLock};
-use std::thread;
-use std::time::Duration;
 
-#[derive(Clone)]
+use super::dispatcher::{worker_type, Dispatcher};
+use super::economy::{CostTable, EconomyConfig};
+use super::events::{EventLog, SimulationEvent};
+use super::inventory::BaseInventory;
+use super::messages::{MessageBus, Order, Report};
+use super::radio::{distance, RadioNetwork, DEFAULT_RADIO_RANGE};
+use super::scenario::{Scenario, StopConditions};
+use super::snapshot::{RobotSnapshot, SimulationSnapshot};
+use std::any::Any;
+use std::collections::{HashSet, VecDeque};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, RwLock};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
 pub struct Simulation {
     pub map: Arc<RwLock<Map>>,
-    pub energy_count: Arc<Mutex<u32>>,
-    pub running: Arc<AtomicBool>,
-    pub speed: Arc<Mutex<u64>>,
+    /// Ressources rapportées à la base, avec leur historique.
+    pub inventory: BaseInventory,
+    /// Cases sur lesquelles au moins un robot est passé.
+    pub explored_tiles: HashSet<(usize, usize)>,
+    pub robots_lost: u32,
+    pub running: bool,
+    /// Délai entre deux ticks en millisecondes quand la simulation est pilotée par l'interface.
+    pub speed: u64,
+    /// Nombre de ticks joués depuis le début de la simulation.
+    pub tick: u64,
+    /// Envoie un explorateur à chaque tick.
+    pub auto_explore: bool,
+    /// Conditions de retour des explorateurs envoyés.
+    pub explorer_config: ExplorerConfig,
+    /// Stratégie donnée par défaut à chaque type de robot construit.
+    pub strategies: StrategyConfig,
+    pub harvester_cargo_capacity: u32,
+    pub scientist_analysis_ticks: u32,
+    /// Prix de construction des robots envoyés depuis la base.
+    pub robot_costs: CostTable,
+    /// Batterie donnée à chaque robot construit.
+    pub battery: BatteryConfig,
+    pub stop_conditions: StopConditions,
     frame_count: u64,
     pub fps: f32,
-    last_frame_time: std::time::Instant,
-    explorer_threads: Arc<Mutex<HashMap<usize, thread::JoinHandle<()>>>>,
-    harvester_threads: Arc<Mutex<HashMap<usize, thread::JoinHandle<()>>>>,
-    pub located_resources: Arc<Mutex<VecDeque<Vec<(usize, usize, Resource)>>>>,
+    last_frame_time: Instant,
+    last_step_time: Instant,
+    started_at: Option<Instant>,
+    robots: Vec<Box<dyn Robot + Send>>,
+    next_robot_id: usize,
+    pub located_resources: VecDeque<Vec<(usize, usize, Resource)>>,
+    /// Carte de référence de la station, fusionnée avec celle de chaque robot qui passe à la base.
+    pub station_map: KnowledgeMap,
+    /// Âge maximal, en ticks, d'une observation encore considérée comme fraîche.
+    pub freshness_ticks: u64,
+    /// Portée radio de la base et des relais ; 0 oblige à rentrer pour signaler.
+    pub radio_range: usize,
+    /// Sites connus, travaux en cours et robots garés à la base.
+    pub dispatcher: Dispatcher,
+    /// Journal des événements depuis la création (ou la reprise) de la simulation.
+    pub events: EventLog,
+    /// Rapports des robots en attente de traitement par la base.
+    bus: MessageBus,
 }
 
 impl Simulation {
     pub fn new(map_seed: u32) -> Self {
-        let map = Arc::new(RwLock::new(Map::new(25, 25, map_seed)));
+        Self::with_map_size(25, 25, map_seed)
+    }
+
+    pub fn with_map_size(width: usize, height: usize, map_seed: u32) -> Self {
+        Self::with_map_config(MapConfig::new(width, height), map_seed)
+    }
 
+    pub fn with_map_config(config: MapConfig, map_seed: u32) -> Self {
+        Self::with_map(Map::with_config(config, map_seed))
+    }
+
+    pub fn with_map(map: Map) -> Self {
+        let economy = EconomyConfig::default();
         Simulation {
-            map,
-            energy_count: Arc::new(Mutex::new(0)),
-            speed: Arc::new(Mutex::new(500)),
-            running: Arc::new(AtomicBool::new(false)),
+            events: EventLog::new(map.clone(), 0),
+            station_map: KnowledgeMap::new(map.width, map.height),
+            freshness_ticks: DEFAULT_FRESHNESS_TICKS,
+            radio_range: DEFAULT_RADIO_RANGE,
+            map: Arc::new(RwLock::new(map)),
+            inventory: BaseInventory::with_funds(economy.starting_funds),
+            explored_tiles: HashSet::new(),
+            robots_lost: 0,
+            speed: 500,
+            running: false,
+            tick: 0,
+            auto_explore: false,
+            explorer_config: ExplorerConfig::default(),
+            strategies: StrategyConfig::default(),
+            harvester_cargo_capacity: DEFAULT_CARGO_CAPACITY,
+            scientist_analysis_ticks: DEFAULT_ANALYSIS_TICKS,
+            robot_costs: economy.costs,
+            battery: BatteryConfig::default(),
+            stop_conditions: StopConditions::default(),
             frame_count: 0,
             fps: 0.0,
-            last_frame_time: std::time::Instant::now(),
-            explorer_threads: Arc::new(Mutex::new(HashMap::new())),
-            harvester_threads: Arc::new(Mutex::new(HashMap::new())),
-            located_resources: Arc::new(Mutex::new(VecDeque::new())),
+            last_frame_time: Instant::now(),
+            last_step_time: Instant::now(),
+            started_at: None,
+            robots: Vec::new(),
+            next_robot_id: 0,
+            located_resources: VecDeque::new(),
+            dispatcher: Dispatcher::default(),
+            bus: MessageBus::new(),
+        }
+    }
+
+    pub fn from_scenario(scenario: &Scenario) -> Self {
+        let mut simulation = Self::with_map_config(scenario.map.clone(), scenario.seed);
+        simulation.speed = scenario.speed;
+        simulation.auto_explore = scenario.auto_explore;
+        simulation.explorer_config = scenario.explorer;
+        simulation.strategies = scenario.strategies;
+        simulation.freshness_ticks = scenario.freshness_ticks;
+        simulation.radio_range = scenario.radio_range;
+        simulation.harvester_cargo_capacity = scenario.harvester_cargo_capacity;
+        simulation.scientist_analysis_ticks = scenario.scientist_analysis_ticks;
+        simulation.inventory = BaseInventory::with_funds(scenario.economy.starting_funds);
+        simulation.robot_costs = scenario.economy.costs;
+        simulation.dispatcher = Dispatcher::new(scenario.dispatch);
+        simulation.battery = scenario.battery;
+        simulation.stop_conditions = scenario.stop.clone();
+
+        for _ in 0..scenario.fleet.explorers {
+            simulation.spawn_robot(RobotType::Explorer, simulation.strategies.explorer, |_| {});
+        }
+        for _ in 0..scenario.fleet.harvesters {
+            simulation.spawn_robot(RobotType::Harvester, simulation.strategies.harvester, |_| {});
+        }
+        simulation
+    }
+
+    pub fn snapshot(&self) -> SimulationSnapshot {
+        SimulationSnapshot {
+            tick: self.tick,
+            map: self.map.read().unwrap().clone(),
+            robots: self
+                .robots
+                .iter()
+                .map(|robot| RobotSnapshot::capture(robot.as_ref()))
+                .collect(),
+            next_robot_id: self.next_robot_id,
+            inventory: self.inventory.clone(),
+            explored_tiles: self.explored_tiles.clone(),
+            robots_lost: self.robots_lost,
+            located_resources: self.located_resources.clone(),
+            station_map: self.station_map.clone(),
+            freshness_ticks: self.freshness_ticks,
+            radio_range: self.radio_range,
+            dispatcher: self.dispatcher.clone(),
+            speed: self.speed,
+            auto_explore: self.auto_explore,
+            explorer_config: self.explorer_config,
+            strategies: self.strategies,
+            harvester_cargo_capacity: self.harvester_cargo_capacity,
+            scientist_analysis_ticks: self.scientist_analysis_ticks,
+            robot_costs: self.robot_costs,
+            battery: self.battery,
+            stop_conditions: self.stop_conditions.clone(),
+        }
+    }
+
+    /// Reconstruit une simulation en pause à partir d'un instantané.
+    pub fn from_snapshot(snapshot: SimulationSnapshot) -> Self {
+        let mut simulation = Self::with_map(snapshot.map);
+        simulation.events.start_tick = snapshot.tick;
+        simulation.tick = snapshot.tick;
+        simulation.robots = snapshot.robots.into_iter().map(RobotSnapshot::restore).collect();
+        {
+            let mut map = simulation.map.write().unwrap();
+            for robot in simulation.robots.iter() {
+                map.place_robot(robot.get_id(), robot.get_type(), robot.get_position());
+            }
         }
+        simulation.next_robot_id = snapshot.next_robot_id;
+        simulation.inventory = snapshot.inventory;
+        simulation.explored_tiles = snapshot.explored_tiles;
+        simulation.robots_lost = snapshot.robots_lost;
+        simulation.located_resources = snapshot.located_resources;
+        simulation.station_map = snapshot.station_map;
+        simulation.freshness_ticks = snapshot.freshness_ticks;
+        simulation.radio_range = snapshot.radio_range;
+        simulation.dispatcher = snapshot.dispatcher;
+        simulation.speed = snapshot.speed;
+        simulation.auto_explore = snapshot.auto_explore;
+        simulation.explorer_config = snapshot.explorer_config;
+        simulation.strategies = snapshot.strategies;
+        simulation.harvester_cargo_capacity = snapshot.harvester_cargo_capacity;
+        simulation.scientist_analysis_ticks = snapshot.scientist_analysis_ticks;
+        simulation.robot_costs = snapshot.robot_costs;
+        simulation.battery = snapshot.battery;
+        simulation.stop_conditions = snapshot.stop_conditions;
+        simulation
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        self.snapshot().save(path)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        SimulationSnapshot::load(path).map(Self::from_snapshot)
     }
 
-    pub fn play(&self) {
-        self.running.store(true, Ordering::SeqCst);
+    /// Indique si l'une des conditions d'arrêt est atteinte après `elapsed` de simulation.
+    pub fn should_stop(&self, elapsed: Duration) -> bool {
+        let stop = &self.stop_conditions;
+        stop.max_ticks.is_some_and(|ticks| self.tick >= ticks)
+            || stop.time_limit().is_some_and(|limit| elapsed >= limit)
+            || stop
+                .energy_target
+                .is_some_and(|target| self.inventory.get(ResourceType::Energy) >= target)
+            || stop
+                .minerals_target
+                .is_some_and(|target| self.inventory.get(ResourceType::Mineral) >= target)
     }
 
-    pub fn pause(&self) {
-        self.running.store(false, Ordering::SeqCst);
+    pub fn play(&mut self) {
+        self.started_at.get_or_insert_with(Instant::now);
+        self.running = true;
     }
 
-    pub fn run(&mut self) {
+    pub fn pause(&mut self) {
+        self.running = false;
+    }
+
+    pub fn run(self) {
         let _ = open_window(self);
     }
 
     pub fn compute_fps(&mut self) {
         self.frame_count += 1;
-        let now = std::time::Instant::now();
+        let now = Instant::now();
         let elapsed = now.duration_since(self.last_frame_time).as_secs_f32();
         if elapsed >= 1.0 {
             self.fps = self.frame_count as f32 / elapsed;
@@ -65,135 +256,429 @@ impl Simulation {
     }
 
     pub fn increase_speed(&mut self) {
-        let mut speed = self.speed.lock().unwrap();
-        if *speed > 100 {
-            *speed -= 100;
+        if self.speed > 100 {
+            self.speed -= 100;
         }
     }
 
     pub fn decrease_speed(&mut self) {
-        let mut speed = self.speed.lock().unwrap();
-        if *speed < 500 {
-            *speed += 100;
+        if self.speed < 500 {
+            self.speed += 100;
         }
     }
 
-    pub fn send_robot<F>(&mut self, robot_type: RobotType, call_bak: F)
+    /// Ce que la station sait de la case, par rapport à la vérité de `map`.
+    pub fn visibility(&self, x: usize, y: usize) -> Visibility {
+        self.station_map.visibility(x, y, self.tick, self.freshness_ticks)
+    }
+
+    /// Pourcentage des cases de la carte connues de la station.
+    pub fn coverage(&self) -> f64 {
+        self.station_map.coverage()
+    }
+
+    /// Base et relais actifs reliés à elle, de proche en proche.
+    pub fn radio_network(&self) -> RadioNetwork {
+        let base = self.map.read().unwrap().base_position;
+        let relays: Vec<_> = self
+            .robots
+            .iter()
+            .filter(|robot| {
+                robot.get_type() == RobotType::Relay
+                    && robot.get_state() == RobotState::Relaying
+                    && !robot.get_battery().is_depleted()
+            })
+            .map(|robot| robot.get_position())
+            .collect();
+        RadioNetwork::build(base, &relays, self.radio_range)
+    }
+
+    /// Poste proposé pour un nouveau relais : la case connue et praticable, à portée
+    /// du réseau, la plus proche du premier explorateur hors de portée.
+    pub fn relay_post(&self) -> Option<(usize, usize)> {
+        let network = self.radio_network();
+        let target = self
+            .robots
+            .iter()
+            .find(|robot| {
+                robot.get_type() == RobotType::Explorer
+                    && robot.get_state() == RobotState::Exploring
+                    && !network.reaches(robot.get_position())
+            })?
+            .get_position();
+        let node = network.nearest_node(target);
+        self.station_map
+            .known()
+            .filter(|&(position, known)| {
+                known.standable
+                    && !network.nodes().contains(&position)
+                    && distance(node, position) <= network.range
+            })
+            .map(|(position, _)| position)
+            .min_by_key(|&position| (distance(position, target), position))
+            .filter(|&position| distance(position, target) < distance(node, target))
+    }
+
+    /// Envoyeur de rapports vers la base, par exemple depuis un autre thread.
+    /// Les rapports sont traités à la fin du prochain tick.
+    pub fn report_sender(&self) -> Sender<Report> {
+        self.bus.sender()
+    }
+
+    pub fn robots(&self) -> &[Box<dyn Robot + Send>] {
+        &self.robots
+    }
+
+    /// Joue un tick si la simulation tourne et que `speed` ms se sont écoulées
+    /// depuis le précédent. Retourne `true` si un tick a été joué.
+    /// La simulation se met en pause dès qu'une condition d'arrêt est atteinte.
+    pub fn update(&mut self) -> bool {
+        if !self.running {
+            return false;
+        }
+        let elapsed = self.started_at.map(|start| start.elapsed()).unwrap_or_default();
+        if self.should_stop(elapsed) {
+            self.pause();
+            return false;
+        }
+        let now = Instant::now();
+        if now.duration_since(self.last_step_time) < Duration::from_millis(self.speed) {
+            return false;
+        }
+        self.last_step_time = now;
+        self.step();
+        true
+    }
+
+    /// Joue un tick : chaque robot actif agit une fois, dans l'ordre de création.
+    /// Les robots envoyés pendant le tick n'agissent qu'à partir du suivant.
+    /// La base traite ensuite les rapports reçus et distribue le travail.
+    /// Un robot dont la batterie se vide hors de la base est perdu et reste sur place.
+    pub fn step(&mut self) {
+        self.tick += 1;
+
+        self.map.write().unwrap().clear_reservations();
+        let network = self.radio_network();
+        let mut robots = std::mem::take(&mut self.robots);
+        for robot in robots.iter_mut() {
+            if robot.get_state() == RobotState::Stranded {
+                continue;
+            }
+            {
+                let mut map = self.map.write().unwrap();
+                robot.check_battery(&map);
+                robot.update(&mut map);
+                robot.endure_hazard(&mut map);
+                robot.remember(&map, self.tick);
+            }
+            for event in robot.pending_events().drain(..) {
+                self.events.record(self.tick, event);
+            }
+            self.explored_tiles.insert(robot.get_position());
+            if robot.get_state() != RobotState::Reporting && network.reaches(robot.get_position()) {
+                self.transmit(robot);
+            }
+            if robot.get_battery().is_depleted()
+                && robot.get_state() != RobotState::Reporting
+                && robot.get_position() != self.map.read().unwrap().base_position
+            {
+                self.strand_robot(robot);
+            }
+            if robot.get_state() == RobotState::Reporting {
+                self.record(SimulationEvent::ReturnedToBase {
+                    robot_id: robot.get_id(),
+                });
+                self.robot_came_back(robot);
+            }
+        }
+        robots.retain(|robot| robot.get_state() != RobotState::Idle);
+
+        robots.append(&mut self.robots);
+        self.robots = robots;
+
+        self.handle_reports();
+        self.dispatch();
+        if self.auto_explore {
+            // Faute de fonds, l'auto-exploration attend simplement un tick suivant.
+            let _ = self.send_robot(RobotType::Explorer, |_| {});
+        }
+    }
+
+    /// Envoie un relais se garer sur `post`, ou suivre sa stratégie si `post` vaut `None`.
+    pub fn send_relay(&mut self, post: Option<(usize, usize)>) -> Result<usize, String> {
+        if let Some((x, y)) = post {
+            let map = self.map.read().unwrap();
+            if x >= map.width || y >= map.height || !map.can_stand_on(x, y) {
+                return Err(format!("cannot post a relay on ({}, {})", x, y));
+            }
+        }
+        self.send_robot(RobotType::Relay, move |robot| {
+            let any: &mut dyn Any = robot.as_mut();
+            if let Some(relay) = any.downcast_mut::<Relay>() {
+                relay.set_post(post);
+            }
+        })
+    }
+
+    pub fn can_afford(&self, robot_type: RobotType) -> bool {
+        self.inventory.can_afford(self.robot_costs.cost(robot_type))
+    }
+
+    /// Construit un robot à la base en prélevant son prix sur le stock.
+    /// Retourne l'identifiant du robot, ou une erreur si les fonds manquent.
+    pub fn send_robot<F>(&mut self, robot_type: RobotType, call_bak: F) -> Result<usize, String>
     where
-        F: FnOnce(&mut Box<dyn Robot + Send>) + Send + 'static,
+        F: FnOnce(&mut Box<dyn Robot + Send>),
     {
-        let map_guard = self.map.read().unwrap();
-        let base_pos = map_guard.base_position;
-        drop(map_guard);
+        self.send_robot_with_strategy(robot_type, self.strategies.get(robot_type), call_bak)
+    }
+
+    /// Comme `send_robot`, avec une stratégie choisie pour ce robot seulement.
+    pub fn send_robot_with_strategy<F>(
+        &mut self,
+        robot_type: RobotType,
+        strategy: StrategyKind,
+        call_bak: F,
+    ) -> Result<usize, String>
+    where
+        F: FnOnce(&mut Box<dyn Robot + Send>),
+    {
+        self.inventory
+            .spend(self.robot_costs.cost(robot_type))
+            .map_err(|error| format!("cannot build {:?}: {}", robot_type, error))?;
+        Ok(self.spawn_robot(robot_type, strategy, call_bak))
+    }
+
+    fn spawn_robot<F>(&mut self, robot_type: RobotType, strategy: StrategyKind, call_bak: F) -> usize
+    where
+        F: FnOnce(&mut Box<dyn Robot + Send>),
+    {
+        let base_pos = self.map.read().unwrap().base_position;
+        let id = self.next_robot_id;
+        self.next_robot_id += 1;
 
         let mut robot: Box<dyn Robot + Send> = match robot_type {
-            RobotType::Explorer => Box::new(Explorer::new(
+            RobotType::Explorer => Box::new(Explorer::with_config(
+                base_pos.0,
+                base_pos.1,
+                id,
+                self.explorer_config,
+            )),
+            RobotType::Harvester => Box::new(Harvester::with_cargo_capacity(
                 base_pos.0,
                 base_pos.1,
-                self.explorer_threads.lock().unwrap().len(),
+                id,
+                self.harvester_cargo_capacity,
             )),
-            RobotType::Harvester => Box::new(Harvester::new(
+            RobotType::Scientist => Box::new(Scientist::with_analysis_ticks(
                 base_pos.0,
                 base_pos.1,
-                self.harvester_threads.lock().unwrap().len(),
+                id,
+                self.scientist_analysis_ticks,
             )),
+            RobotType::Relay => Box::new(Relay::new(base_pos.0, base_pos.1, id)),
         };
 
-        if robot_type == RobotType::Harvester {
+        if robot_type != RobotType::Explorer {
             call_bak(&mut robot);
         }
+        robot.set_strategy(strategy.build());
+        robot.sync_knowledge(&mut self.station_map);
+        *robot.battery() = Battery::new(self.battery);
 
-        let map = Arc::clone(&self.map);
-        let running = Arc::clone(&self.running);
-        let speed = Arc::clone(&self.speed);
-        let mut self_clone = self.clone();
-        let robot_id = robot.get_id();
-        let thread_handle = thread::spawn(move || loop {
-            if robot.get_state() == RobotState::Reporting {
-                self_clone.robot_came_back(&mut robot);
-            }
-            let sleep_time = {
-                let speed = speed.lock().unwrap();
-                *speed
-            };
-            if !running.load(Ordering::SeqCst) {
-                thread::sleep(Duration::from_millis(sleep_time));
-                continue;
-            }
-
-            let mut map_guard = map.write().unwrap();
-            robot.update(&mut map_guard);
-            drop(map_guard);
-
-            thread::sleep(Duration::from_millis(sleep_time));
-            if robot.get_state() == RobotState::Idle {
-                break;
-            }
+        self.map
+            .write()
+            .unwrap()
+            .place_robot(id, robot_type, base_pos);
+        self.record(SimulationEvent::RobotSpawned {
+            robot_id: id,
+            robot_type,
+            position: base_pos,
         });
+        self.robots.push(robot);
+        id
+    }
 
-        match robot_type {
-            RobotType::Explorer => {
-                self.frame_count += 1;
-                let mut explorer_threads = self.explorer_threads.lock().unwrap();
-                explorer_threads.insert(robot_id, thread_handle);
+    /// Répartiteur : annule les travaux sur des sites vidés, puis sert les sites en attente
+    /// du meilleur score au moins bon, avec un robot garé à la base ou, à défaut, un robot neuf.
+    /// Un site dont le robot ne peut pas être construit reste dans la file.
+    fn dispatch(&mut self) {
+        for robot_id in self.dispatcher.cancel_depleted(&self.station_map) {
+            if let Some(robot) = self.robots.iter_mut().find(|robot| robot.get_id() == robot_id) {
+                robot.receive(Order::Cancel);
             }
-            RobotType::Harvester => {
-                let mut harvester_threads = self.harvester_threads.lock().unwrap();
-                harvester_threads.insert(robot_id, thread_handle);
+        }
+
+        let base = self.map.read().unwrap().base_position;
+        for site in self.dispatcher.ranked_sites(base) {
+            let robot_type = worker_type(site.resource.resource_type);
+            let order = Order::HarvestRequest {
+                position: site.position,
+                resource: site.resource,
+            };
+            let assigned = match self.dispatcher.take_idle(robot_type) {
+                Some(robot_id) => {
+                    let robot = self
+                        .robots
+                        .iter_mut()
+                        .find(|robot| robot.get_id() == robot_id)
+                        .unwrap();
+                    robot.receive(order);
+                    Some(robot_id)
+                }
+                None => self
+                    .send_robot(robot_type, move |worker| worker.receive(order))
+                    .ok(),
+            };
+            if let Some(robot_id) = assigned {
+                self.dispatcher.assign(robot_id, site);
             }
         }
     }
 
+    fn record(&mut self, event: SimulationEvent) {
+        self.events.record(self.tick, event);
+    }
+
     fn robot_came_back(&mut self, robot: &mut Box<dyn Robot + Send>) {
-        let mut self_clone = self.clone();
+        robot.battery().recharge();
+        robot.sync_knowledge(&mut self.station_map);
+        if let Some(resource) = robot.take_cargo() {
+            self.bus.send(Report::CargoDelivered {
+                robot_id: robot.get_id(),
+                resource,
+            });
+        }
         match robot.get_type() {
             RobotType::Explorer => {
-                let found_resource = robot.get_current_resource();
-                if let Some((res_x, res_y, resource, _)) = found_resource {
-                    let mut located_resources = self.located_resources.lock().unwrap();
-                    let resource_exists = located_resources.iter().any(|resources| {
-                        resources.iter().any(|(x, y, _)| *x == res_x && *y == res_y)
-                    });
-                    if !resource_exists {
-                        located_resources.push_back(vec![(res_x, res_y, resource.clone())]);
-                        self_clone.send_robot(RobotType::Harvester, move |harvester| {
-                            harvester.set_target_resource(Some((
-                                res_x,
-                                res_y,
-                                resource,
-                                true,
-                            )));
-                        });
-                    }
-                }
-                self.join_thread(robot);
+                self.send_findings(robot);
+                self.retire_robot(robot);
             }
-            RobotType::Harvester => {
-                let target = robot.get_current_resource();
-                if let Some((_, _, resource, remind)) = target {
-                    match resource.resource_type {
-                        crate::maps::tile::ResourceType::Energy => {
-                            let mut energy_count = self_clone.energy_count.lock().unwrap();
-                            *energy_count += resource.scale;
-                        }
-                        _ => {}
-                    }
+            RobotType::Harvester => match robot.get_current_resource() {
+                Some((_, _, _, true)) => robot.set_state(RobotState::Harvesting),
+                _ => self.park_robot(robot),
+            },
+            // Un scientifique rappelé avant la fin de l'analyse y retourne.
+            RobotType::Scientist => match robot.get_current_resource() {
+                Some(_) => robot.set_state(RobotState::Analyzing),
+                None => self.park_robot(robot),
+            },
+            // Un relais rentré recharger repart vers son poste.
+            RobotType::Relay => robot.set_state(RobotState::Relaying),
+        }
+    }
+
+    /// Un robot à portée radio synchronise sa carte et signale ses découvertes sans rentrer.
+    fn transmit(&mut self, robot: &mut Box<dyn Robot + Send>) {
+        robot.sync_knowledge(&mut self.station_map);
+        let sent = self.send_findings(robot);
+        if sent > 0 {
+            self.record(SimulationEvent::ReportTransmitted {
+                robot_id: robot.get_id(),
+                position: robot.get_position(),
+                resources: sent,
+            });
+        }
+    }
+
+    /// Envoie à la base un `ResourceFound` par ressource repérée ; retourne leur nombre.
+    fn send_findings(&mut self, robot: &mut Box<dyn Robot + Send>) -> usize {
+        let findings = robot.take_findings();
+        for &(x, y, resource) in &findings {
+            self.bus.send(Report::ResourceFound {
+                robot_id: robot.get_id(),
+                position: (x, y),
+                resource,
+            });
+        }
+        findings.len()
+    }
 
-                    match remind {
-                        true => robot.set_state(RobotState::Harvesting),
-                        _ => self.join_thread(robot),
+    /// Contrôleur de la base : traite les rapports du tick, dans leur ordre d'arrivée.
+    /// Les ressources encore inconnues sont ajoutées à `located_resources`, regroupées
+    /// par robot, et à la file du répartiteur.
+    fn handle_reports(&mut self) {
+        let mut reporter = None;
+        let mut report = Vec::new();
+        for message in self.bus.receive() {
+            match message {
+                Report::ResourceFound {
+                    robot_id,
+                    position: (x, y),
+                    resource,
+                } => {
+                    if !self.dispatcher.add_site((x, y), resource) {
+                        continue;
                     }
+                    if reporter != Some(robot_id) && !report.is_empty() {
+                        self.located_resources.push_back(std::mem::take(&mut report));
+                    }
+                    reporter = Some(robot_id);
+                    report.push((x, y, resource));
+                }
+                Report::CargoDelivered { resource, .. } => {
+                    self.credit(resource.resource_type, resource.scale);
+                }
+                Report::RobotLost { robot_id, position } => {
+                    self.robots_lost += 1;
+                    self.dispatcher.abandon(robot_id);
+                    self.record(SimulationEvent::RobotLost { robot_id, position });
                 }
             }
         }
+        if !report.is_empty() {
+            self.located_resources.push_back(report);
+        }
+    }
+
+    fn strand_robot(&mut self, robot: &mut Box<dyn Robot + Send>) {
+        self.bus.send(Report::RobotLost {
+            robot_id: robot.get_id(),
+            position: robot.get_position(),
+        });
+        robot.set_state(RobotState::Stranded);
+    }
+
+    fn credit(&mut self, resource_type: ResourceType, amount: u32) {
+        self.inventory.credit(self.tick, resource_type, amount);
+        self.record(SimulationEvent::ResourceCredited {
+            resource_type,
+            amount,
+        });
+    }
+
+    /// Gare un robot à la base, à la disposition du répartiteur.
+    /// Arrivé à côté de la base, il y entre pour ne pas bloquer le passage.
+    fn park_robot(&mut self, robot: &mut Box<dyn Robot + Send>) {
+        let from = robot.get_position();
+        let base = self.map.read().unwrap().base_position;
+        if from != base {
+            self.map
+                .write()
+                .unwrap()
+                .move_robot(robot.get_id(), robot.get_type(), from, base);
+            robot.set_position(base.0, base.1);
+            self.record(SimulationEvent::RobotMoved {
+                robot_id: robot.get_id(),
+                robot_type: robot.get_type(),
+                from,
+                to: base,
+            });
+        }
+        self.dispatcher.park(robot.get_id(), robot.get_type());
+        robot.set_state(RobotState::Standby);
     }
 
-    fn join_thread(&mut self, robot: &mut Box<dyn Robot + Send>) {
-        self.map.write().unwrap().set(MapTile::new(
-            robot.get_position().0,
-            robot.get_position().1,
-            TileType::Empty,
-        ));
+    fn retire_robot(&mut self, robot: &mut Box<dyn Robot + Send>) {
+        let position = robot.get_position();
+        self.map
+            .write()
+            .unwrap()
+            .remove_robot(robot.get_id(), position);
+        self.record(SimulationEvent::RobotRetired {
+            robot_id: robot.get_id(),
+            position: robot.get_position(),
+        });
         robot.set_state(RobotState::Idle);
     }
 }
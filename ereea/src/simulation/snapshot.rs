@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::{HashSet, VecDeque};
+use std::path::Path;
+
+use crate::maps::knowledge::KnowledgeMap;
+use crate::maps::map::Map;
+use crate::maps::tile::Resource;
+use crate::robots::battery::BatteryConfig;
+use crate::robots::explorer::{Explorer, ExplorerConfig};
+use crate::robots::harvester::Harvester;
+use crate::robots::relay::Relay;
+use crate::robots::robot::{Robot, RobotType};
+use crate::robots::scientist::Scientist;
+use crate::robots::strategies::StrategyConfig;
+
+use super::dispatcher::Dispatcher;
+use super::economy::CostTable;
+use super::inventory::BaseInventory;
+use super::scenario::StopConditions;
+
+/// État complet d'un robot, quel que soit son type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RobotSnapshot {
+    Explorer(Explorer),
+    Harvester(Harvester),
+    Scientist(Scientist),
+    Relay(Relay),
+}
+
+impl RobotSnapshot {
+    pub fn capture(robot: &(dyn Robot + Send)) -> Self {
+        let any: &dyn Any = robot;
+        match robot.get_type() {
+            RobotType::Explorer => {
+                RobotSnapshot::Explorer(any.downcast_ref::<Explorer>().unwrap().clone())
+            }
+            RobotType::Harvester => {
+                RobotSnapshot::Harvester(any.downcast_ref::<Harvester>().unwrap().clone())
+            }
+            RobotType::Scientist => {
+                RobotSnapshot::Scientist(any.downcast_ref::<Scientist>().unwrap().clone())
+            }
+            RobotType::Relay => RobotSnapshot::Relay(any.downcast_ref::<Relay>().unwrap().clone()),
+        }
+    }
+
+    pub fn restore(self) -> Box<dyn Robot + Send> {
+        match self {
+            RobotSnapshot::Explorer(explorer) => Box::new(explorer),
+            RobotSnapshot::Harvester(harvester) => Box::new(harvester),
+            RobotSnapshot::Scientist(scientist) => Box::new(scientist),
+            RobotSnapshot::Relay(relay) => Box::new(relay),
+        }
+    }
+}
+
+/// Instantané d'une simulation, suffisant pour la reprendre à l'identique.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationSnapshot {
+    pub tick: u64,
+    pub map: Map,
+    pub robots: Vec<RobotSnapshot>,
+    pub next_robot_id: usize,
+    pub inventory: BaseInventory,
+    pub explored_tiles: HashSet<(usize, usize)>,
+    pub robots_lost: u32,
+    pub located_resources: VecDeque<Vec<(usize, usize, Resource)>>,
+    pub station_map: KnowledgeMap,
+    pub freshness_ticks: u64,
+    pub radio_range: usize,
+    pub dispatcher: Dispatcher,
+    pub speed: u64,
+    pub auto_explore: bool,
+    pub explorer_config: ExplorerConfig,
+    pub strategies: StrategyConfig,
+    pub harvester_cargo_capacity: u32,
+    pub scientist_analysis_ticks: u32,
+    pub robot_costs: CostTable,
+    pub battery: BatteryConfig,
+    pub stop_conditions: StopConditions,
+}
+
+impl SimulationSnapshot {
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let content = serde_json::to_string(self).map_err(|error| error.to_string())?;
+        std::fs::write(path, content)
+            .map_err(|error| format!("cannot write {}: {}", path.display(), error))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+        serde_json::from_str(&content).map_err(|error| error.to_string())
+    }
+}
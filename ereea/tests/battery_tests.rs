@@ -0,0 +1,105 @@
+use ereea::maps::biome::Biome;
+use ereea::maps::map::Map;
+use ereea::maps::tile::{MapTile, TileType};
+use ereea::robots::battery::{Battery, BatteryConfig};
+use ereea::robots::explorer::Explorer;
+use ereea::robots::robot::{Robot, RobotState};
+use ereea::simulation::events::SimulationEvent;
+use ereea::simulation::simulation::Simulation;
+use ereea::simulation::snapshot::RobotSnapshot;
+
+fn open_map() -> Map {
+    let mut map = Map::new(10, 10, 1);
+    for y in 0..map.height {
+        for x in 0..map.width {
+            map.set(MapTile::new(x, y, TileType::Empty));
+            map.set_elevation(x, y, 0.0);
+            map.set_biome(x, y, Biome::Plains);
+        }
+    }
+    map.base_position = (0, 0);
+    map.set(MapTile::new(0, 0, TileType::Base));
+    map
+}
+
+#[test]
+fn test_battery_consumption_and_recharge() {
+    let mut battery = Battery::new(BatteryConfig {
+        capacity: 5,
+        move_cost: 2,
+        action_cost: 3,
+        reserve: 0,
+    });
+
+    assert!(battery.consume(3));
+    assert!(battery.can_move());
+    assert!(!battery.consume(3));
+    assert!(battery.consume(2));
+    assert!(battery.is_depleted());
+
+    battery.recharge();
+    assert_eq!(battery.charge, 5);
+}
+
+#[test]
+fn test_moves_drain_battery_and_low_battery_forces_return() {
+    let mut map = open_map();
+    let mut explorer = Explorer::new(4, 4, 0);
+    *explorer.battery() = Battery::new(BatteryConfig {
+        capacity: 20,
+        move_cost: 1,
+        action_cost: 2,
+        reserve: 5,
+    });
+
+    assert!(explorer.move_to(5, 4, &mut map));
+    assert_eq!(explorer.get_battery().charge, 19);
+
+    explorer.check_battery(&map);
+    assert_eq!(explorer.get_state(), RobotState::Exploring);
+
+    explorer.battery().charge = 14;
+    explorer.check_battery(&map);
+    assert_eq!(explorer.get_state(), RobotState::ReturningToBase);
+}
+
+#[test]
+fn test_empty_battery_strands_robot_on_the_map() {
+    let simulation = Simulation::with_map(open_map());
+    let mut snapshot = simulation.snapshot();
+    let mut explorer = Explorer::new(6, 6, 0);
+    explorer.battery().charge = 0;
+    snapshot.robots = vec![RobotSnapshot::Explorer(explorer)];
+    snapshot.next_robot_id = 1;
+    let mut simulation = Simulation::from_snapshot(snapshot);
+
+    simulation.step();
+    simulation.step();
+
+    assert_eq!(simulation.robots_lost, 1);
+    assert_eq!(simulation.robots().len(), 1);
+    assert_eq!(simulation.robots()[0].get_state(), RobotState::Stranded);
+    assert_eq!(simulation.robots()[0].get_position(), (6, 6));
+    assert!(simulation.map.read().unwrap().is_occupied(6, 6));
+}
+
+#[test]
+fn test_hazardous_biome_drains_the_battery() {
+    let mut map = open_map();
+    map.config.biomes.ice.hazard_rate = 1.0;
+    map.set_biome(3, 3, Biome::Ice);
+    let mut explorer = Explorer::new(3, 3, 0);
+
+    explorer.endure_hazard(&mut map);
+
+    assert_eq!(explorer.get_battery().charge, 200 - map.config.biomes.hazard_drain);
+    assert!(matches!(
+        explorer.pending_events().as_slice(),
+        [SimulationEvent::HazardStruck { position: (3, 3), .. }]
+    ));
+
+    // Les plaines sont sans danger.
+    explorer.set_position(4, 4);
+    explorer.endure_hazard(&mut map);
+    assert_eq!(explorer.pending_events().len(), 1);
+}
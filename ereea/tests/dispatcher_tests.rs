@@ -0,0 +1,123 @@
+use ereea::maps::knowledge::{KnowledgeMap, KnownTile};
+use ereea::maps::map::Map;
+use ereea::maps::tile::{MapTile, Resource, ResourceType, TileType};
+use ereea::robots::robot::{RobotState, RobotType};
+use ereea::simulation::dispatcher::{DispatchConfig, Dispatcher, Site};
+use ereea::simulation::economy::CostTable;
+use ereea::simulation::messages::Report;
+use ereea::simulation::simulation::Simulation;
+
+fn energy(amount: u32) -> Resource {
+    Resource::new(amount, ResourceType::Energy)
+}
+
+#[test]
+fn test_sites_are_ranked_by_priority_quantity_and_distance() {
+    let mut dispatcher = Dispatcher::new(DispatchConfig::default());
+    dispatcher.add_site((10, 0), energy(10));
+    dispatcher.add_site((2, 0), energy(10));
+    dispatcher.add_site((3, 0), energy(30));
+    dispatcher.add_site((12, 0), Resource::new(5, ResourceType::Science));
+    assert!(!dispatcher.add_site((2, 0), energy(10)));
+
+    let order: Vec<_> = dispatcher
+        .ranked_sites((0, 0))
+        .iter()
+        .map(|site| site.position)
+        .collect();
+
+    // Scores : (3,0) 22, (2,0) 13, (12,0) 10.5, (10,0) 5.
+    assert_eq!(order, vec![(3, 0), (2, 0), (12, 0), (10, 0)]);
+}
+
+#[test]
+fn test_idle_robots_are_reused_and_lost_jobs_requeued() {
+    let mut dispatcher = Dispatcher::default();
+    dispatcher.add_site((4, 4), energy(10));
+    let site = Site {
+        position: (4, 4),
+        resource: energy(10),
+    };
+    dispatcher.park(3, RobotType::Harvester);
+    dispatcher.park(5, RobotType::Scientist);
+
+    assert_eq!(dispatcher.take_idle(RobotType::Harvester), Some(3));
+    assert_eq!(dispatcher.take_idle(RobotType::Harvester), None);
+    dispatcher.assign(3, site);
+    assert!(dispatcher.queue().is_empty());
+
+    dispatcher.abandon(3);
+    assert_eq!(dispatcher.queue(), &[site]);
+    assert!(dispatcher.jobs().is_empty());
+}
+
+#[test]
+fn test_depleted_sites_are_cancelled() {
+    let mut dispatcher = Dispatcher::default();
+    dispatcher.add_site((1, 1), energy(10));
+    dispatcher.add_site((2, 2), energy(10));
+    dispatcher.assign(
+        7,
+        Site {
+            position: (2, 2),
+            resource: energy(10),
+        },
+    );
+    let mut station = KnowledgeMap::new(4, 4);
+    for position in [(1, 1), (2, 2)] {
+        station.observe(
+            position.0,
+            position.1,
+            KnownTile {
+                tile: TileType::Empty,
+                standable: true,
+                tick: 3,
+            },
+        );
+    }
+
+    assert_eq!(dispatcher.cancel_depleted(&station), vec![7]);
+    assert!(dispatcher.queue().is_empty());
+    assert!(dispatcher.jobs().is_empty());
+}
+
+#[test]
+fn test_parked_harvester_takes_the_next_site() {
+    let mut map = Map::new(20, 20, 1);
+    for y in 0..20 {
+        for x in 0..20 {
+            if (x, y) != map.base_position {
+                map.set(MapTile::new(x, y, TileType::Empty));
+            }
+        }
+    }
+    let (base_x, base_y) = map.base_position;
+    let first = (base_x.checked_sub(2).unwrap_or(base_x + 2), base_y);
+    let second = (base_x, base_y.checked_sub(2).unwrap_or(base_y + 2));
+    for position in [first, second] {
+        map.set(MapTile::new(position.0, position.1, TileType::Resource(energy(3))));
+    }
+    let mut simulation = Simulation::with_map(map);
+    simulation.robot_costs = CostTable::free();
+    let sender = simulation.report_sender();
+    let found = |position| Report::ResourceFound {
+        robot_id: 0,
+        position,
+        resource: energy(3),
+    };
+
+    sender.send(found(first)).unwrap();
+    for _ in 0..30 {
+        simulation.step();
+    }
+    assert_eq!(simulation.robots().len(), 1);
+    assert_eq!(simulation.robots()[0].get_state(), RobotState::Standby);
+    assert_eq!(simulation.dispatcher.idle(), &[(0, RobotType::Harvester)]);
+
+    sender.send(found(second)).unwrap();
+    simulation.step();
+
+    assert_eq!(simulation.robots().len(), 1);
+    assert_eq!(simulation.robots()[0].get_state(), RobotState::Harvesting);
+    assert_eq!(simulation.dispatcher.jobs()[&0].position, second);
+}
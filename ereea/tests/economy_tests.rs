@@ -0,0 +1,57 @@
+use ereea::maps::tile::ResourceType;
+use ereea::robots::robot::RobotType;
+use ereea::simulation::economy::{Cost, CostTable, EconomyConfig};
+use ereea::simulation::inventory::BaseInventory;
+use ereea::simulation::scenario::{FleetConfig, Scenario};
+use ereea::simulation::simulation::Simulation;
+
+#[test]
+fn test_send_robot_charges_the_cost_table() {
+    let mut simulation = Simulation::new(4);
+    simulation.inventory = BaseInventory::with_funds(Cost::new(12, 5));
+    simulation.robot_costs = CostTable {
+        explorer: Cost::new(5, 0),
+        harvester: Cost::new(5, 5),
+        scientist: Cost::new(10, 5),
+        relay: Cost::new(10, 10),
+    };
+
+    simulation.send_robot(RobotType::Explorer, |_| {}).unwrap();
+    assert_eq!(simulation.inventory.stock(ResourceType::Energy), 7);
+    assert!(simulation.can_afford(RobotType::Harvester));
+    assert!(!simulation.can_afford(RobotType::Scientist));
+}
+
+#[test]
+fn test_send_robot_fails_without_funds_and_keeps_stock() {
+    let mut simulation = Simulation::new(4);
+    simulation.inventory = BaseInventory::with_funds(Cost::new(3, 0));
+
+    let error = simulation.send_robot(RobotType::Explorer, |_| {}).unwrap_err();
+
+    assert!(error.contains("not enough resources"));
+    assert_eq!(simulation.inventory.stock(ResourceType::Energy), 3);
+    assert!(simulation.robots().is_empty());
+}
+
+#[test]
+fn test_scenario_fleet_is_free_and_sets_economy() {
+    let scenario = Scenario {
+        economy: EconomyConfig {
+            starting_funds: Cost::new(0, 0),
+            costs: CostTable::default(),
+        },
+        fleet: FleetConfig {
+            explorers: 3,
+            harvesters: 0,
+        },
+        ..Scenario::default()
+    };
+
+    let mut simulation = Simulation::from_scenario(&scenario);
+    simulation.auto_explore = true;
+    simulation.step();
+
+    assert_eq!(simulation.robots().len(), 3);
+    assert!(simulation.send_robot(RobotType::Explorer, |_| {}).is_err());
+}
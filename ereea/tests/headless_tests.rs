@@ -0,0 +1,56 @@
+use ereea::simulation::headless::{HeadlessOptions, OutputFormat, run_headless};
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+#[test]
+fn test_parse_headless_options() {
+    let options = HeadlessOptions::from_args(args(
+        "--headless --seed 7 --width 30 --height 20 --explorers 3 --ticks 50 --format json",
+    ))
+    .unwrap();
+
+    assert_eq!(options.scenario.seed, 7);
+    assert_eq!(
+        (options.scenario.map.width, options.scenario.map.height),
+        (30, 20)
+    );
+    assert_eq!(options.scenario.fleet.explorers, 3);
+    assert_eq!(options.scenario.stop.max_ticks, Some(50));
+    assert_eq!(options.format, OutputFormat::Json);
+}
+
+#[test]
+fn test_parse_rejects_unknown_option() {
+    assert!(HeadlessOptions::from_args(args("--bogus 1")).is_err());
+    assert!(HeadlessOptions::from_args(args("--ticks")).is_err());
+}
+
+#[test]
+fn test_command_line_overrides_scenario_file() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/scenarios/example.toml");
+    let options =
+        HeadlessOptions::from_args(args(&format!("--scenario {} --seed 9", path))).unwrap();
+
+    assert_eq!(options.scenario.seed, 9);
+    assert_eq!(options.scenario.map.width, 40);
+    assert_eq!(options.scenario.fleet.explorers, 6);
+}
+
+#[test]
+fn test_headless_run_is_reproducible() {
+    let options = HeadlessOptions::from_args(args("--seed 11 --explorers 3 --ticks 150")).unwrap();
+
+    let summary1 = run_headless(&options).unwrap();
+    let summary2 = run_headless(&options).unwrap();
+
+    assert_eq!(summary1.ticks, 150);
+    assert!(summary1.tiles_explored > 0);
+    assert_eq!(summary1, summary2);
+    assert!(
+        summary1
+            .render(OutputFormat::Json)
+            .contains("\"energy_collected\"")
+    );
+}
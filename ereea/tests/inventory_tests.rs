@@ -0,0 +1,40 @@
+use ereea::maps::tile::ResourceType;
+use ereea::robots::robot::RobotType;
+use ereea::simulation::inventory::BaseInventory;
+use ereea::simulation::simulation::Simulation;
+
+#[test]
+fn test_inventory_counts_every_resource_type_with_history() {
+    let mut inventory = BaseInventory::new();
+    inventory.credit(3, ResourceType::Energy, 5);
+    inventory.credit(4, ResourceType::Mineral, 10);
+    inventory.credit(7, ResourceType::Energy, 2);
+
+    assert_eq!(inventory.get(ResourceType::Energy), 7);
+    assert_eq!(inventory.get(ResourceType::Mineral), 10);
+    assert_eq!(inventory.get(ResourceType::Science), 0);
+    assert_eq!(inventory.totals().count(), ResourceType::ALL.len());
+    assert_eq!(inventory.history().len(), 3);
+    assert_eq!(inventory.get_at(ResourceType::Energy, 5), 5);
+    assert_eq!(inventory.get_at(ResourceType::Energy, 2), 0);
+}
+
+#[test]
+fn test_simulation_inventory_matches_credited_events() {
+    let mut simulation = Simulation::new(3);
+    for _ in 0..4 {
+        simulation.send_robot(RobotType::Explorer, |_| {}).unwrap();
+    }
+    for _ in 0..500 {
+        simulation.step();
+    }
+
+    let minerals = simulation.inventory.get(ResourceType::Mineral);
+    assert!(minerals > 0);
+    let last = simulation.inventory.history().last().unwrap();
+    assert!(last.tick <= simulation.tick);
+    assert_eq!(
+        simulation.inventory.history().iter().map(|record| record.amount).sum::<u32>(),
+        simulation.inventory.totals().map(|(_, total)| total).sum::<u32>()
+    );
+}
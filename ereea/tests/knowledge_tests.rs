@@ -0,0 +1,92 @@
+use ereea::maps::knowledge::{KnowledgeMap, KnownTile, MergeReport, Visibility};
+use ereea::maps::tile::{Resource, ResourceType, TileType};
+use ereea::robots::robot::RobotType;
+use ereea::simulation::simulation::Simulation;
+
+fn seen(tile: TileType, tick: u64) -> KnownTile {
+    KnownTile {
+        tile,
+        standable: tile == TileType::Empty,
+        tick,
+    }
+}
+
+#[test]
+fn test_newest_observation_wins_the_merge() {
+    let energy = TileType::Resource(Resource::new(10, ResourceType::Energy));
+    let mut station = KnowledgeMap::new(5, 5);
+    station.observe(2, 2, seen(energy, 3));
+
+    // Un robot est passé après la récolte : la case est vide depuis le tick 8.
+    let mut robot = KnowledgeMap::new(5, 5);
+    robot.observe(2, 2, seen(TileType::Empty, 8));
+    robot.observe(4, 4, seen(TileType::Terrain, 6));
+
+    assert_eq!(station.merge(&robot), 2);
+    assert_eq!(station.get(2, 2), Some(seen(TileType::Empty, 8)));
+    assert_eq!(station.resources().count(), 0);
+
+    // Une observation plus ancienne ne remplace rien.
+    let mut late = KnowledgeMap::new(5, 5);
+    late.observe(2, 2, seen(energy, 5));
+    assert_eq!(station.merge(&late), 0);
+    assert_eq!(station.get(2, 2), Some(seen(TileType::Empty, 8)));
+}
+
+#[test]
+fn test_sync_pushes_then_pulls() {
+    let mut station = KnowledgeMap::new(4, 4);
+    station.observe(0, 0, seen(TileType::Empty, 1));
+    station.observe(1, 0, seen(TileType::Empty, 1));
+    let mut robot = KnowledgeMap::new(4, 4);
+    robot.observe(3, 3, seen(TileType::Terrain, 2));
+
+    let report = robot.sync(&mut station);
+
+    assert_eq!(report, MergeReport { pulled: 2, pushed: 1 });
+    assert_eq!(robot, station);
+    assert_eq!(station.known_count(), 3);
+}
+
+#[test]
+fn test_visibility_ages_and_coverage_counts_known_tiles() {
+    let mut station = KnowledgeMap::new(4, 5);
+    station.observe(1, 1, seen(TileType::Empty, 10));
+
+    assert_eq!(station.visibility(0, 0, 10, 5), Visibility::Unknown);
+    assert_eq!(
+        station.visibility(1, 1, 15, 5),
+        Visibility::Fresh(seen(TileType::Empty, 10))
+    );
+    assert_eq!(
+        station.visibility(1, 1, 16, 5),
+        Visibility::Stale(seen(TileType::Empty, 10))
+    );
+    assert_eq!(station.coverage(), 5.0);
+}
+
+#[test]
+fn test_returning_explorers_fill_the_station_map() {
+    let mut simulation = Simulation::with_map_size(20, 20, 3);
+    simulation.send_robot(RobotType::Explorer, |_| {}).unwrap();
+    assert_eq!(simulation.station_map.known_count(), 0);
+
+    let explorer_out = |simulation: &Simulation| {
+        simulation
+            .robots()
+            .iter()
+            .any(|robot| robot.get_type() == RobotType::Explorer)
+    };
+    for _ in 0..300 {
+        simulation.step();
+        if !explorer_out(&simulation) {
+            break;
+        }
+    }
+
+    assert!(!explorer_out(&simulation));
+    assert!(simulation.station_map.known_count() > 1);
+    assert!(simulation.coverage() > 0.0);
+    let (x, y) = simulation.map.read().unwrap().base_position;
+    assert!(simulation.station_map.get(x, y).is_some());
+}
@@ -1,4 +1,10 @@
-use ereea::maps::{map::Map};
+use ereea::maps::biome::{Biome, BiomeConfig, BiomeProfile};
+use ereea::maps::config::{MapConfig, ResourceDistribution};
+use ereea::maps::map::Map;
+use ereea::maps::tile::{MapTile, Resource, ResourceType, TileType};
+use ereea::maps::topology::Topology;
+use ereea::robots::robot::RobotType;
+use noise::Perlin;
 
 #[test]
 fn test_map_generation_reproducibility() {
@@ -8,4 +14,242 @@ fn test_map_generation_reproducibility() {
     assert_eq!(map1.seed, map2.seed);
     assert_eq!(map1.width, map2.width);
     assert_eq!(map1.height, map2.height);
+    assert_eq!(map1.base_position, map2.base_position);
+    for y in 0..map1.height {
+        for x in 0..map1.width {
+            assert_eq!(map1.get(x, y), map2.get(x, y));
+        }
+    }
+}
+
+#[test]
+fn test_map_generation_depends_on_seed() {
+    let map1 = Map::new(20, 20, 42);
+    let map2 = Map::new(20, 20, 43);
+
+    assert!(map1.grid != map2.grid || map1.base_position != map2.base_position);
+}
+
+#[test]
+fn test_map_config_matches_default_generation() {
+    let map1 = Map::new(20, 20, 42);
+    let map2 = Map::with_config(MapConfig::new(20, 20), 42);
+
+    assert_eq!(map1.grid, map2.grid);
+    assert_eq!(map1.base_position, map2.base_position);
+}
+
+#[test]
+fn test_map_config_resource_distribution() {
+    // Des biomes neutres, pour que les probabilités s'appliquent telles quelles.
+    let neutral = BiomeConfig {
+        crater: BiomeProfile::default(),
+        ice: BiomeProfile::default(),
+        rock_field: BiomeProfile::default(),
+        ..BiomeConfig::default()
+    };
+    let config = MapConfig::new(30, 30)
+        .resource(ResourceDistribution::new(ResourceType::Energy, 0.0, 10))
+        .resource(ResourceDistribution::new(ResourceType::Mineral, 1.0, 3))
+        .biomes(neutral);
+    let map = Map::with_config(config, 5);
+
+    let resources: Vec<Resource> = map
+        .grid
+        .iter()
+        .filter_map(|tile| match tile.tile {
+            TileType::Resource(resource) => Some(resource),
+            _ => None,
+        })
+        .collect();
+    assert!(!resources.is_empty());
+    assert!(resources
+        .iter()
+        .all(|resource| resource.resource_type == ResourceType::Mineral && resource.scale == 3));
+}
+
+#[test]
+fn test_map_config_validation() {
+    assert!(MapConfig::new(2, 10).validate().is_err());
+    assert!(MapConfig::new(10, 10).resource_probability(1.5).validate().is_err());
+    assert!(MapConfig::new(10, 10).validate().is_ok());
+    assert!(MapConfig::new(9, 10).topology(Topology::Sphere).validate().is_err());
+}
+
+#[test]
+fn test_neighbours_follow_the_topology() {
+    let neighbours = |topology, position| {
+        let map = Map::with_config(MapConfig::new(10, 6).topology(topology), 42);
+        let mut neighbours: Vec<_> = map.neighbours(position).collect();
+        neighbours.sort();
+        neighbours
+    };
+
+    assert_eq!(neighbours(Topology::Bounded, (0, 0)), vec![(0, 1), (1, 0)]);
+    assert_eq!(
+        neighbours(Topology::Torus, (0, 0)),
+        vec![(0, 1), (0, 5), (1, 0), (9, 0)]
+    );
+    // Au pôle nord, monter ramène sur la première ligne, une demi-largeur plus loin.
+    assert_eq!(
+        neighbours(Topology::Sphere, (2, 0)),
+        vec![(1, 0), (2, 1), (3, 0), (7, 0)]
+    );
+}
+
+#[test]
+fn test_distance_takes_the_shortest_way_around() {
+    let map = |topology| Map::with_config(MapConfig::new(10, 6).topology(topology), 42);
+
+    assert_eq!(map(Topology::Bounded).distance((0, 0), (9, 5)), 14);
+    assert_eq!(map(Topology::Torus).distance((0, 0), (9, 5)), 2);
+    assert_eq!(map(Topology::Sphere).distance((1, 0), (6, 0)), 1);
+    assert_eq!(map(Topology::Sphere).distance((0, 1), (9, 4)), 4);
+}
+
+#[test]
+fn test_noise_is_seamless_across_wrapped_edges() {
+    let perlin = Perlin::new(42);
+    let sample = |topology: Topology, position| topology.sample(&perlin, position, 6.0, 30, 20);
+
+    for y in 0..20 {
+        let wrapped = sample(Topology::Torus, (30, y)) - sample(Topology::Torus, (0, y));
+        assert!(wrapped.abs() < 1e-9);
+        let wrapped = sample(Topology::Sphere, (30, y)) - sample(Topology::Sphere, (0, y));
+        assert!(wrapped.abs() < 1e-9);
+    }
+    for x in 0..30 {
+        let wrapped = sample(Topology::Torus, (x, 20)) - sample(Topology::Torus, (x, 0));
+        assert!(wrapped.abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_robot_layer_keeps_terrain_and_shares_base() {
+    let mut map = Map::new(20, 20, 42);
+    let base = map.base_position;
+
+    map.place_robot(0, RobotType::Explorer, base);
+    map.place_robot(1, RobotType::Harvester, base);
+    assert_eq!(map.robots_at(base.0, base.1).len(), 2);
+    assert!(map.can_enter(base.0, base.1));
+    assert_eq!(map.get(base.0, base.1).tile, TileType::Base);
+    assert_eq!(map.visible_tile(base.0, base.1), TileType::Robot(RobotType::Explorer));
+
+    map.set(MapTile::new(0, 0, TileType::Empty));
+    map.move_robot(0, RobotType::Explorer, base, (0, 0));
+    assert!(map.is_occupied(0, 0));
+    assert_eq!(map.get(0, 0).tile, TileType::Empty);
+    assert_eq!(map.robots_at(base.0, base.1), &[(1, RobotType::Harvester)]);
+
+    assert_eq!(map.remove_robot(0, (0, 0)), Some(RobotType::Explorer));
+    assert!(map.robots_at(0, 0).is_empty());
+}
+
+#[test]
+fn test_default_generation_places_science_sites() {
+    let map = Map::new(60, 60, 42);
+
+    assert!(map.grid.iter().any(|tile| matches!(
+        tile.tile,
+        TileType::Resource(Resource {
+            resource_type: ResourceType::Science,
+            ..
+        })
+    )));
+}
+
+#[test]
+fn test_elevation_follows_the_terrain_noise() {
+    let map = Map::new(20, 20, 42);
+
+    for y in 0..map.height {
+        for x in 0..map.width {
+            if map.get(x, y).tile == TileType::Terrain {
+                assert!(map.elevation(x, y) > map.config.threshold);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cliffs_block_moves_and_heights_see_further() {
+    let mut map = Map::new(10, 10, 42);
+    for y in 0..map.height {
+        for x in 0..map.width {
+            map.set(MapTile::new(x, y, TileType::Empty));
+            map.set_elevation(x, y, 0.0);
+        }
+    }
+    map.set_elevation(5, 5, 0.6);
+    map.set_elevation(6, 5, 0.3);
+
+    assert!(map.is_cliff((4, 5), (5, 5)));
+    assert!(!map.is_adjacent((4, 5), (5, 5)));
+    assert!(map.is_adjacent((6, 5), (5, 5)));
+    assert_eq!(map.slope_penalty((7, 5), (6, 5)), 1);
+    assert_eq!(map.slope_penalty((6, 5), (7, 5)), 0);
+
+    assert_eq!(map.visible_from((1, 1)).len(), 5);
+    assert_eq!(map.vision_range((5, 5)), 4);
+    assert!(map.visible_from((5, 5)).contains(&(5, 9)));
+}
+
+#[test]
+fn test_biomes_are_layered_and_reproducible() {
+    let map1 = Map::new(40, 30, 3);
+    let map2 = Map::new(40, 30, 3);
+
+    let mut found = Vec::new();
+    for y in 0..map1.height {
+        for x in 0..map1.width {
+            assert_eq!(map1.biome(x, y), map2.biome(x, y));
+            if !found.contains(&map1.biome(x, y)) {
+                found.push(map1.biome(x, y));
+            }
+        }
+    }
+    assert_eq!(found.len(), Biome::ALL.len());
+}
+
+#[test]
+fn test_biome_profiles_drive_resources_and_movement() {
+    let barren = BiomeConfig {
+        plains: BiomeProfile::new(0.0, 0.0, 1.0),
+        crater: BiomeProfile::new(0.0, 0.0, 1.0),
+        ice: BiomeProfile::new(0.0, 0.0, 1.0),
+        rock_field: BiomeProfile::new(0.0, 0.0, 1.0),
+        ..BiomeConfig::default()
+    };
+    let map = Map::with_config(MapConfig::new(30, 30).biomes(barren), 5);
+    assert!(map
+        .grid
+        .iter()
+        .all(|tile| !matches!(tile.tile, TileType::Resource(_))));
+
+    let mut map = Map::new(10, 10, 42);
+    for x in 0..3 {
+        map.set(MapTile::new(x, 0, TileType::Empty));
+        map.set_elevation(x, 0, 0.0);
+        map.set_biome(x, 0, Biome::Plains);
+    }
+    map.set_biome(2, 0, Biome::RockField);
+
+    assert_eq!(map.movement_cost(1, 0), Some(1.0));
+    assert_eq!(map.movement_cost(2, 0), Some(2.0));
+    assert_eq!(map.step_penalty((0, 0), (1, 0)), 0);
+    assert_eq!(map.step_penalty((1, 0), (2, 0)), 1);
+}
+
+#[test]
+fn test_biome_config_validation() {
+    let mut biomes = BiomeConfig::default();
+    biomes.ice.hazard_rate = 1.5;
+    assert!(MapConfig::new(10, 10).biomes(biomes).validate().is_err());
+
+    let biomes = BiomeConfig {
+        octaves: 0,
+        ..BiomeConfig::default()
+    };
+    assert!(MapConfig::new(10, 10).biomes(biomes).validate().is_err());
 }
@@ -0,0 +1,78 @@
+use ereea::maps::tile::{Resource, ResourceType};
+use ereea::robots::harvester::Harvester;
+use ereea::robots::robot::{Robot, RobotState};
+use ereea::simulation::inventory::BaseInventory;
+use ereea::simulation::messages::{Order, Report};
+use ereea::simulation::simulation::Simulation;
+use std::thread;
+
+#[test]
+fn test_reports_from_another_thread_reach_the_base() {
+    let mut simulation = Simulation::new(4);
+    let sender = simulation.report_sender();
+    let before = simulation.inventory.get(ResourceType::Energy);
+
+    thread::spawn(move || {
+        sender
+            .send(Report::CargoDelivered {
+                robot_id: 7,
+                resource: Resource::new(4, ResourceType::Energy),
+            })
+            .unwrap();
+        sender
+            .send(Report::RobotLost {
+                robot_id: 8,
+                position: (1, 1),
+            })
+            .unwrap();
+    })
+    .join()
+    .unwrap();
+    simulation.step();
+
+    assert_eq!(simulation.inventory.get(ResourceType::Energy), before + 4);
+    assert_eq!(simulation.robots_lost, 1);
+}
+
+#[test]
+fn test_found_resources_are_grouped_by_robot_and_deduplicated() {
+    let mut simulation = Simulation::new(4);
+    // Sans fonds, les sites restent en attente d'un robot.
+    simulation.inventory = BaseInventory::new();
+    let sender = simulation.report_sender();
+    let energy = Resource::new(10, ResourceType::Energy);
+    for (robot_id, position) in [(1, (2, 2)), (1, (3, 2)), (2, (3, 2)), (2, (5, 5))] {
+        sender
+            .send(Report::ResourceFound {
+                robot_id,
+                position,
+                resource: energy,
+            })
+            .unwrap();
+    }
+
+    simulation.step();
+
+    let reports: Vec<Vec<(usize, usize)>> = simulation
+        .located_resources
+        .iter()
+        .map(|report| report.iter().map(|&(x, y, _)| (x, y)).collect())
+        .collect();
+    assert_eq!(reports, vec![vec![(2, 2), (3, 2)], vec![(5, 5)]]);
+    assert_eq!(simulation.dispatcher.queue().len(), 3);
+}
+
+#[test]
+fn test_harvest_request_gives_the_harvester_its_target() {
+    let mut harvester = Harvester::new(0, 0, 1);
+    harvester.set_state(RobotState::ReturningToBase);
+    let resource = Resource::new(10, ResourceType::Mineral);
+
+    harvester.receive(Order::HarvestRequest {
+        position: (4, 3),
+        resource,
+    });
+
+    assert_eq!(harvester.get_current_resource(), Some((4, 3, resource, true)));
+    assert_eq!(harvester.get_state(), RobotState::Harvesting);
+}
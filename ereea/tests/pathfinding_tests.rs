@@ -0,0 +1,180 @@
+use ereea::maps::config::{MapConfig, MovementCosts};
+use ereea::maps::biome::Biome;
+use ereea::maps::map::Map;
+use ereea::maps::movement::Movement;
+use ereea::maps::tile::{MapTile, Resource, ResourceType, TileType};
+use ereea::maps::topology::Topology;
+use ereea::pathfinding::astar::{Heuristic, find_path};
+use ereea::pathfinding::path_cache::{PathCache, Step};
+use ereea::robots::robot::RobotType;
+
+fn open_map(config: MapConfig) -> Map {
+    let mut map = Map::with_config(config, 1);
+    for y in 0..map.height {
+        for x in 0..map.width {
+            map.set(MapTile::new(x, y, TileType::Empty));
+            map.set_elevation(x, y, 0.0);
+            map.set_biome(x, y, Biome::Plains);
+        }
+    }
+    map
+}
+
+fn wall(map: &mut Map, x: usize, ys: std::ops::Range<usize>) {
+    for y in ys {
+        map.set(MapTile::new(x, y, TileType::Terrain));
+    }
+}
+
+#[test]
+fn test_find_path_is_shortest_on_open_map() {
+    let map = open_map(MapConfig::new(10, 10));
+
+    let path = find_path(&map, (1, 1), (6, 4), Heuristic::Manhattan).unwrap();
+
+    assert_eq!(path.first(), Some(&(1, 1)));
+    assert_eq!(path.last(), Some(&(6, 4)));
+    assert_eq!(path.len(), 9);
+}
+
+#[test]
+fn test_find_path_goes_around_walls_and_reaches_resources() {
+    let mut map = open_map(MapConfig::new(10, 10));
+    wall(&mut map, 4, 0..8);
+    map.set(MapTile::new(
+        8,
+        1,
+        TileType::Resource(Resource::new(10, ResourceType::Energy)),
+    ));
+
+    let path = find_path(&map, (1, 1), (8, 1), Heuristic::Octile).unwrap();
+
+    assert!(
+        path.iter()
+            .all(|&(x, y)| map.get(x, y).tile != TileType::Terrain)
+    );
+    assert!(path.contains(&(4, 8)));
+    assert_eq!(path.last(), Some(&(8, 1)));
+}
+
+#[test]
+fn test_find_path_prefers_cheaper_terrain() {
+    let costs = MovementCosts {
+        terrain: Some(50.0),
+        ..MovementCosts::default()
+    };
+    let mut map = open_map(MapConfig::new(10, 10).movement_costs(costs));
+    wall(&mut map, 4, 0..8);
+
+    let path = find_path(&map, (1, 1), (7, 1), Heuristic::Manhattan).unwrap();
+    assert!(path.contains(&(4, 8)));
+
+    wall(&mut map, 4, 0..10);
+    let path = find_path(&map, (1, 1), (7, 1), Heuristic::Manhattan).unwrap();
+    assert_eq!(path.len(), 7);
+}
+
+#[test]
+fn test_find_path_unreachable() {
+    let mut map = open_map(MapConfig::new(10, 10));
+    wall(&mut map, 4, 0..10);
+
+    assert_eq!(find_path(&map, (1, 1), (7, 1), Heuristic::Manhattan), None);
+}
+
+#[test]
+fn test_path_cache_replans_when_blocked() {
+    let mut map = open_map(MapConfig::new(10, 10));
+    let mut cache = PathCache::default();
+
+    let Step::Move(first) = cache.next_step((0, 0), (5, 0), &map) else {
+        panic!("expected a move");
+    };
+    assert_eq!(first, (1, 0));
+    assert_eq!(cache.remaining(), 5);
+
+    map.set(MapTile::new(2, 0, TileType::Terrain));
+    let Step::Move(second) = cache.next_step(first, (5, 0), &map) else {
+        panic!("expected a move");
+    };
+    assert_eq!(second, (1, 1));
+    assert_eq!(cache.next_step((4, 0), (5, 0), &map), Step::Arrived);
+}
+
+#[test]
+fn test_passability_separates_standing_targeting_and_occupation() {
+    let mut map = open_map(MapConfig::new(6, 6));
+    map.set(MapTile::new(1, 1, TileType::Base));
+    map.place_robot(0, RobotType::Explorer, (2, 1));
+    map.set(MapTile::new(
+        3,
+        1,
+        TileType::Resource(Resource::new(5, ResourceType::Energy)),
+    ));
+    map.set(MapTile::new(4, 1, TileType::Terrain));
+
+    assert!(map.can_enter(1, 1));
+    assert!(map.can_stand_on(2, 1) && map.is_occupied(2, 1) && !map.can_enter(2, 1));
+    assert!(!map.can_stand_on(3, 1) && map.can_target(3, 1));
+    assert!(!map.can_target(4, 1));
+    assert!(find_path(&map, (0, 0), (4, 1), Heuristic::Manhattan).is_none());
+}
+
+#[test]
+fn test_reservation_prevents_two_robots_on_same_tile() {
+    let mut map = open_map(MapConfig::new(6, 6));
+
+    assert!(map.reserve(2, 2));
+    assert!(!map.reserve(2, 2));
+    assert!(map.is_occupied(2, 2));
+
+    map.clear_reservations();
+    assert!(map.can_enter(2, 2));
+}
+
+#[test]
+fn test_path_goes_through_robots_instead_of_a_long_detour() {
+    let mut map = open_map(MapConfig::new(10, 3));
+    wall(&mut map, 5, 0..1);
+    wall(&mut map, 5, 2..3);
+    map.place_robot(0, RobotType::Harvester, (5, 1));
+
+    let path = find_path(&map, (0, 1), (9, 1), Heuristic::Manhattan).unwrap();
+
+    assert!(path.contains(&(5, 1)));
+}
+
+#[test]
+fn test_find_path_wraps_around_a_torus() {
+    let mut map = open_map(MapConfig::new(10, 10).topology(Topology::Torus));
+    wall(&mut map, 5, 0..10);
+
+    let path = find_path(&map, (1, 1), (8, 1), Heuristic::Manhattan).unwrap();
+
+    assert_eq!(path, vec![(1, 1), (0, 1), (9, 1), (8, 1)]);
+}
+
+#[test]
+fn test_eight_way_paths_take_diagonals_without_cutting_corners() {
+    let mut map = open_map(MapConfig::new(10, 10).movement(Movement::EightWay));
+
+    let path = find_path(&map, (1, 1), (5, 5), Heuristic::Octile).unwrap();
+    assert_eq!(path, vec![(1, 1), (2, 2), (3, 3), (4, 4), (5, 5)]);
+
+    // Le relief en (2, 1) interdit de passer en diagonale de (1, 1) à (2, 2).
+    map.set(MapTile::new(2, 1, TileType::Terrain));
+    assert!(!map.is_adjacent((1, 1), (2, 2)));
+    let path = find_path(&map, (1, 1), (2, 2), Heuristic::Octile).unwrap();
+    assert_eq!(path, vec![(1, 1), (1, 2), (2, 2)]);
+}
+
+#[test]
+fn test_eight_way_distance_counts_diagonal_steps() {
+    let four_way = open_map(MapConfig::new(10, 10));
+    let eight_way = open_map(MapConfig::new(10, 10).movement(Movement::EightWay));
+
+    assert_eq!(four_way.neighbours((5, 5)).count(), 4);
+    assert_eq!(eight_way.neighbours((5, 5)).count(), 8);
+    assert_eq!(four_way.distance((1, 1), (5, 3)), 6);
+    assert_eq!(eight_way.distance((1, 1), (5, 3)), 4);
+}
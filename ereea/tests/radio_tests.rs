@@ -0,0 +1,82 @@
+use ereea::maps::map::Map;
+use ereea::maps::tile::{MapTile, Resource, ResourceType, TileType};
+use ereea::robots::robot::{RobotState, RobotType};
+use ereea::simulation::economy::CostTable;
+use ereea::simulation::radio::RadioNetwork;
+use ereea::simulation::simulation::Simulation;
+
+/// Simulation sur une carte sans obstacle ni ressource, hormis la base.
+fn open_simulation(width: usize, height: usize) -> Simulation {
+    let mut map = Map::new(width, height, 1);
+    for y in 0..height {
+        for x in 0..width {
+            if (x, y) != map.base_position {
+                map.set(MapTile::new(x, y, TileType::Empty));
+            }
+        }
+    }
+    let mut simulation = Simulation::with_map(map);
+    simulation.robot_costs = CostTable::free();
+    simulation
+}
+
+#[test]
+fn test_relays_chain_from_the_base() {
+    let network = RadioNetwork::build((0, 0), &[(10, 0), (5, 0), (30, 30)], 5);
+
+    assert_eq!(network.nodes(), &[(0, 0), (5, 0), (10, 0)]);
+    assert!(network.reaches((14, 1)));
+    assert!(!network.reaches((30, 29)));
+    assert_eq!(network.nearest_node((9, 3)), (10, 0));
+}
+
+#[test]
+fn test_explorer_in_range_reports_without_returning() {
+    let mut simulation = open_simulation(20, 20);
+    simulation.explorer_config.findings_to_report = 10;
+    let (base_x, base_y) = simulation.map.read().unwrap().base_position;
+    let site = (base_x.checked_sub(1).unwrap_or(base_x + 1), base_y);
+    simulation.map.write().unwrap().set(MapTile::new(
+        site.0,
+        site.1,
+        TileType::Resource(Resource::new(10, ResourceType::Energy)),
+    ));
+    simulation.send_robot(RobotType::Explorer, |_| {}).unwrap();
+
+    for _ in 0..3 {
+        simulation.step();
+    }
+
+    assert_eq!(simulation.located_resources.len(), 1);
+    assert_eq!(simulation.located_resources[0][0].0, site.0);
+    let explorer = &simulation.robots()[0];
+    assert_eq!(explorer.get_type(), RobotType::Explorer);
+    assert_eq!(explorer.get_state(), RobotState::Exploring);
+}
+
+#[test]
+fn test_parked_relay_extends_the_range() {
+    let mut simulation = open_simulation(30, 30);
+    simulation.radio_range = 4;
+    let (base_x, base_y) = simulation.map.read().unwrap().base_position;
+    let post = if base_x >= 15 { (base_x - 4, base_y) } else { (base_x + 4, base_y) };
+    let far = if base_x >= 15 { (base_x - 8, base_y) } else { (base_x + 8, base_y) };
+    assert!(!simulation.radio_network().reaches(far));
+
+    simulation.send_relay(Some(post)).unwrap();
+    for _ in 0..20 {
+        simulation.step();
+    }
+
+    assert_eq!(simulation.robots()[0].get_position(), post);
+    assert_eq!(simulation.robots()[0].get_state(), RobotState::Relaying);
+    assert!(simulation.radio_network().reaches(far));
+}
+
+#[test]
+fn test_relay_cannot_be_posted_out_of_the_map() {
+    let mut simulation = open_simulation(10, 10);
+
+    assert!(simulation.send_relay(Some((10, 3))).is_err());
+    assert!(simulation.robots().is_empty());
+}
@@ -0,0 +1,62 @@
+use ereea::maps::tile::ResourceType;
+use ereea::robots::robot::RobotType;
+use ereea::simulation::events::{EventLog, SimulationEvent};
+use ereea::simulation::replay::Replay;
+use ereea::simulation::simulation::Simulation;
+
+fn recorded_simulation(ticks: usize) -> Simulation {
+    let mut simulation = Simulation::new(17);
+    for _ in 0..5 {
+        simulation.send_robot(RobotType::Explorer, |_| {}).unwrap();
+    }
+    for _ in 0..ticks {
+        simulation.step();
+    }
+    simulation
+}
+
+#[test]
+fn test_events_are_ordered_and_cover_spawns() {
+    let simulation = recorded_simulation(40);
+    let events = &simulation.events.events;
+
+    assert!(events.windows(2).all(|pair| pair[0].tick <= pair[1].tick));
+    let spawned = events
+        .iter()
+        .filter(|recorded| matches!(recorded.event, SimulationEvent::RobotSpawned { .. }))
+        .count();
+    assert!(spawned >= 5);
+    assert!(
+        events
+            .iter()
+            .any(|recorded| matches!(recorded.event, SimulationEvent::RobotMoved { .. }))
+    );
+}
+
+#[test]
+fn test_replay_rebuilds_final_map() {
+    let simulation = recorded_simulation(300);
+    let replay = Replay::new(simulation.events.clone());
+
+    let state = replay.state_at(simulation.tick);
+    assert_eq!(state.map.grid, simulation.map.read().unwrap().grid);
+    assert_eq!(state.inventory.get(ResourceType::Energy), simulation.inventory.get(ResourceType::Energy));
+    assert_eq!(state.inventory.get(ResourceType::Mineral), simulation.inventory.get(ResourceType::Mineral));
+    assert_eq!(state.robots.len(), simulation.robots().len());
+}
+
+#[test]
+fn test_replay_at_intermediate_tick_matches_live_run() {
+    let mut simulation = recorded_simulation(50);
+    let grid_at_50 = simulation.map.read().unwrap().grid.clone();
+    for _ in 0..50 {
+        simulation.step();
+    }
+
+    let path = std::env::temp_dir().join(format!("ereea-events-{}.json", std::process::id()));
+    simulation.events.save(&path).unwrap();
+    let log = EventLog::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(Replay::new(log).state_at(50).map.grid, grid_at_50);
+}
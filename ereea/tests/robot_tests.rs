@@ -1,10 +1,14 @@
-use ereea::robots::explorer::Explorer;
-use ereea::robots::robot::Robot;
+use ereea::robots::explorer::{Explorer, ExplorerConfig};
+use ereea::robots::robot::{Robot, RobotState};
+use ereea::robots::scientist::Scientist;
+use ereea::robots::strategies::{BuiltinStrategy, Strategy, StrategyKind};
+use ereea::maps::biome::Biome;
 use ereea::maps::map::Map;
+use ereea::maps::tile::{MapTile, Resource, ResourceType, TileType};
 
 #[test]
 fn test_robot_creation_and_movement() {
-    let mut map = Map::new(10, 10, 1);
+    let mut map = open_map(10, 10);
     let mut robot = Explorer::new(2, 3, 42);
 
     // Déplace le robot dans la map (supposons un mouvement simple ici)
@@ -15,3 +19,135 @@ fn test_robot_creation_and_movement() {
     assert_eq!(y, 5);
 }
 
+#[test]
+fn test_explorer_path_reproducibility() {
+    let mut map1 = Map::new(20, 20, 7);
+    let mut map2 = Map::new(20, 20, 7);
+    let (base_x, base_y) = map1.base_position;
+    let mut explorer1 = Explorer::new(base_x, base_y, 0);
+    let mut explorer2 = Explorer::new(base_x, base_y, 0);
+
+    for _ in 0..50 {
+        explorer1.update(&mut map1);
+        explorer2.update(&mut map2);
+        assert_eq!(explorer1.get_position(), explorer2.get_position());
+        assert_eq!(explorer1.get_state(), explorer2.get_state());
+    }
+    assert_eq!(map1.grid, map2.grid);
+}
+
+#[test]
+fn test_scientist_stays_on_site_before_reporting() {
+    let mut map = Map::new(10, 10, 1);
+    for y in 0..map.height {
+        for x in 0..map.width {
+            map.set(MapTile::new(x, y, TileType::Empty));
+        }
+    }
+    let site = Resource::new(5, ResourceType::Science);
+    map.set(MapTile::new(3, 2, TileType::Resource(site)));
+    let mut scientist = Scientist::with_analysis_ticks(2, 2, 0, 3);
+    scientist.set_target_resource(Some((3, 2, site, false)));
+
+    for _ in 0..2 {
+        scientist.update(&mut map);
+        assert_eq!(scientist.get_state(), RobotState::Analyzing);
+    }
+    assert_eq!(scientist.progress(), 2);
+    assert_eq!(map.get(3, 2).tile, TileType::Resource(site));
+
+    scientist.update(&mut map);
+    assert_eq!(scientist.get_state(), RobotState::ReturningToBase);
+    assert_eq!(map.get(3, 2).tile, TileType::Empty);
+    assert_eq!(scientist.get_current_resource(), None);
+    assert_eq!(scientist.take_cargo(), Some(site));
+}
+
+fn open_map(width: usize, height: usize) -> Map {
+    let mut map = Map::new(width, height, 1);
+    for y in 0..map.height {
+        for x in 0..map.width {
+            map.set(MapTile::new(x, y, TileType::Empty));
+            map.set_elevation(x, y, 0.0);
+            map.set_biome(x, y, Biome::Plains);
+        }
+    }
+    map
+}
+
+#[test]
+fn test_climbing_takes_extra_ticks_and_battery() {
+    let mut map = open_map(10, 10);
+    map.set_elevation(3, 2, 0.3);
+    let mut explorer = Explorer::new(2, 2, 0);
+    let charge = explorer.get_battery().charge;
+
+    assert!(!explorer.move_to(3, 2, &mut map));
+    assert_eq!(explorer.get_position(), (2, 2));
+    assert!(explorer.move_to(3, 2, &mut map));
+    assert_eq!(explorer.get_position(), (3, 2));
+    assert_eq!(explorer.get_battery().charge, charge - 2);
+
+    // La descente ne coûte rien de plus.
+    assert!(explorer.move_to(2, 2, &mut map));
+}
+
+#[test]
+fn test_frontier_explorer_covers_new_ground_every_step() {
+    let mut map = open_map(12, 12);
+    let mut explorer = Explorer::with_config(6, 6, 0, ExplorerConfig::default());
+
+    let seen = |explorer: &Explorer| match explorer.get_strategy() {
+        BuiltinStrategy::Frontier(frontier) => frontier.seen().len(),
+        _ => panic!("explorers use the frontier strategy by default"),
+    };
+
+    for _ in 0..20 {
+        let seen_before = seen(&explorer);
+        explorer.update(&mut map);
+        assert!(seen(&explorer) > seen_before || seen_before == 0);
+        assert_eq!(explorer.get_state(), RobotState::Exploring);
+    }
+}
+
+#[test]
+fn test_frontier_explorer_returns_with_enough_findings() {
+    let mut map = open_map(12, 12);
+    for x in [3, 5, 7] {
+        map.set(MapTile::new(
+            x,
+            5,
+            TileType::Resource(Resource::new(10, ResourceType::Energy)),
+        ));
+    }
+    let config = ExplorerConfig {
+        findings_to_report: 2,
+        ..ExplorerConfig::default()
+    };
+    let mut explorer = Explorer::with_config(4, 6, 0, config);
+
+    explorer.update(&mut map);
+    assert_eq!(explorer.findings().len(), 0);
+    assert_eq!(explorer.get_state(), RobotState::Exploring);
+    for _ in 0..200 {
+        if explorer.get_state() != RobotState::Exploring {
+            break;
+        }
+        explorer.update(&mut map);
+    }
+
+    assert_eq!(explorer.get_state(), RobotState::ReturningToBase);
+    assert_eq!(explorer.findings().len(), 2);
+}
+
+#[test]
+fn test_strategy_is_chosen_per_robot() {
+    let mut map = open_map(12, 12);
+    let mut explorer = Explorer::with_config(6, 6, 0, ExplorerConfig::default());
+    explorer.set_strategy(StrategyKind::RandomWalk.build());
+
+    explorer.update(&mut map);
+
+    assert_eq!(explorer.get_strategy().kind(), StrategyKind::RandomWalk);
+    assert_eq!(explorer.get_state(), RobotState::Exploring);
+}
@@ -0,0 +1,52 @@
+use ereea::maps::tile::ResourceType;
+use ereea::simulation::scenario::Scenario;
+use ereea::simulation::simulation::Simulation;
+
+#[test]
+fn test_load_example_scenario() {
+    let scenario = Scenario::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/scenarios/example.toml"
+    ))
+    .unwrap();
+
+    assert_eq!(scenario.seed, 42);
+    assert_eq!((scenario.map.width, scenario.map.height), (40, 30));
+    assert_eq!(
+        scenario.map.resources[1].resource_type,
+        ResourceType::Mineral
+    );
+    assert_eq!(scenario.fleet.explorers, 6);
+    assert_eq!(scenario.stop.max_ticks, Some(2000));
+}
+
+#[test]
+fn test_scenario_json_round_trip_and_defaults() {
+    let scenario: Scenario =
+        serde_json::from_str(r#"{ "seed": 3, "fleet": { "explorers": 2 } }"#).unwrap();
+    assert_eq!(scenario.map, Scenario::default().map);
+    assert_eq!(scenario.harvester_cargo_capacity, 5);
+
+    let json = serde_json::to_string(&scenario).unwrap();
+    assert_eq!(serde_json::from_str::<Scenario>(&json).unwrap(), scenario);
+}
+
+#[test]
+fn test_simulation_from_scenario() {
+    let mut scenario = Scenario {
+        seed: 8,
+        speed: 200,
+        ..Default::default()
+    };
+    scenario.fleet.explorers = 2;
+    scenario.stop.max_ticks = Some(10);
+
+    let mut simulation = Simulation::from_scenario(&scenario);
+    assert_eq!(simulation.robots().len(), 2);
+    assert_eq!(simulation.speed, 200);
+
+    while !simulation.should_stop(std::time::Duration::ZERO) {
+        simulation.step();
+    }
+    assert_eq!(simulation.tick, 10);
+}
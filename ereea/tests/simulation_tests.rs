@@ -1,3 +1,4 @@
+use ereea::robots::robot::RobotType;
 use ereea::simulation::simulation::Simulation;
 
 #[test]
@@ -6,3 +7,55 @@ fn test_simulation_initialization() {
     let map = sim.map.read().unwrap();
     assert_eq!(map.seed, 123);
 }
+
+#[test]
+fn test_step_advances_every_robot_once() {
+    let mut sim = Simulation::new(123);
+    sim.send_robot(RobotType::Explorer, |_| {}).unwrap();
+    sim.send_robot(RobotType::Explorer, |_| {}).unwrap();
+
+    sim.step();
+
+    assert_eq!(sim.tick, 1);
+    let ids: Vec<usize> = sim.robots().iter().map(|robot| robot.get_id()).collect();
+    assert_eq!(ids, vec![0, 1]);
+}
+
+#[test]
+fn test_simulation_reproducibility() {
+    let mut sim1 = Simulation::new(99);
+    let mut sim2 = Simulation::new(99);
+    for _ in 0..3 {
+        sim1.send_robot(RobotType::Explorer, |_| {}).unwrap();
+        sim2.send_robot(RobotType::Explorer, |_| {}).unwrap();
+    }
+
+    for _ in 0..200 {
+        sim1.step();
+        sim2.step();
+    }
+
+    assert_eq!(sim1.map.read().unwrap().grid, sim2.map.read().unwrap().grid);
+    assert_eq!(sim1.inventory, sim2.inventory);
+    let positions = |sim: &Simulation| -> Vec<(usize, usize)> {
+        sim.robots().iter().map(|robot| robot.get_position()).collect()
+    };
+    assert_eq!(positions(&sim1), positions(&sim2));
+}
+
+#[test]
+fn test_robots_never_share_a_tile_outside_the_base() {
+    let mut simulation = Simulation::new(3);
+    for _ in 0..6 {
+        simulation.send_robot(RobotType::Explorer, |_| {}).unwrap();
+    }
+    for _ in 0..200 {
+        simulation.step();
+        let base = simulation.map.read().unwrap().base_position;
+        let mut seen = std::collections::HashSet::new();
+        for robot in simulation.robots() {
+            let position = robot.get_position();
+            assert!(position == base || seen.insert(position));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use ereea::robots::robot::RobotType;
+use ereea::simulation::simulation::Simulation;
+use ereea::simulation::snapshot::SimulationSnapshot;
+
+fn running_simulation() -> Simulation {
+    let mut simulation = Simulation::new(21);
+    for _ in 0..4 {
+        simulation.send_robot(RobotType::Explorer, |_| {}).unwrap();
+    }
+    for _ in 0..60 {
+        simulation.step();
+    }
+    simulation
+}
+
+fn robot_states(simulation: &Simulation) -> Vec<(usize, (usize, usize), String)> {
+    simulation
+        .robots()
+        .iter()
+        .map(|robot| {
+            (
+                robot.get_id(),
+                robot.get_position(),
+                format!("{:?}{:?}", robot.get_state(), robot.get_current_resource()),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_snapshot_restores_identical_state() {
+    let simulation = running_simulation();
+    let restored = Simulation::from_snapshot(simulation.snapshot());
+
+    assert_eq!(restored.tick, simulation.tick);
+    assert_eq!(restored.inventory, simulation.inventory);
+    assert_eq!(restored.located_resources, simulation.located_resources);
+    assert_eq!(
+        restored.map.read().unwrap().grid,
+        simulation.map.read().unwrap().grid
+    );
+    assert_eq!(robot_states(&restored), robot_states(&simulation));
+}
+
+#[test]
+fn test_restored_simulation_continues_identically() {
+    let mut simulation = running_simulation();
+    let path = std::env::temp_dir().join(format!("ereea-snapshot-{}.json", std::process::id()));
+    simulation.save(&path).unwrap();
+    let mut restored = Simulation::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    for _ in 0..100 {
+        simulation.step();
+        restored.step();
+    }
+
+    assert_eq!(
+        restored.map.read().unwrap().grid,
+        simulation.map.read().unwrap().grid
+    );
+    assert_eq!(robot_states(&restored), robot_states(&simulation));
+    assert_eq!(restored.inventory, simulation.inventory);
+}
+
+#[test]
+fn test_snapshot_json_round_trip() {
+    let snapshot = running_simulation().snapshot();
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let decoded: SimulationSnapshot = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(decoded.tick, snapshot.tick);
+    assert_eq!(decoded.robots.len(), snapshot.robots.len());
+    assert_eq!(decoded.map.base_position, snapshot.map.base_position);
+}
@@ -0,0 +1,106 @@
+use ereea::maps::tile::{Resource, ResourceType, TileType};
+use ereea::maps::movement::Movement;
+use ereea::maps::topology::Topology;
+use ereea::robots::strategies::frontier::Frontier;
+use ereea::robots::strategies::nearest_resource::NearestResource;
+use ereea::robots::strategies::spiral::Spiral;
+use ereea::robots::strategies::{Action, Perception, Strategy, VisibleTile};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Perception d'un robot en `position` sur une carte vide, sans ressource voulue.
+fn perception_at(position: (usize, usize), width: usize, height: usize) -> Perception {
+    let mut perception = Perception {
+        position,
+        base_position: (0, 0),
+        topology: Topology::Bounded,
+        movement: Movement::FourWay,
+        width,
+        height,
+        visible: Vec::new(),
+        wanted: vec![ResourceType::Energy],
+    };
+    perception.visible = std::iter::once(position)
+        .chain(perception.neighbours(position))
+        .map(|position| VisibleTile {
+            position,
+            tile: TileType::Empty,
+            standable: true,
+        })
+        .collect();
+    perception
+}
+
+#[test]
+fn test_nearest_resource_works_adjacent_resource() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let mut perception = perception_at((5, 5), 10, 10);
+    let energy = TileType::Resource(Resource::new(10, ResourceType::Energy));
+    for tile in perception.visible.iter_mut() {
+        if tile.position == (6, 5) {
+            tile.tile = energy;
+            tile.standable = false;
+        }
+    }
+    let mut strategy = NearestResource::default();
+
+    assert_eq!(strategy.decide(&perception, &mut rng), Action::Work((6, 5)));
+    assert_eq!(strategy.known(), &[(6, 5)]);
+}
+
+#[test]
+fn test_nearest_resource_ignores_unwanted_resources() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let mut perception = perception_at((5, 5), 10, 10);
+    let science = TileType::Resource(Resource::new(5, ResourceType::Science));
+    for tile in perception.visible.iter_mut() {
+        if tile.position == (6, 5) {
+            tile.tile = science;
+            tile.standable = false;
+        }
+    }
+    let mut strategy = NearestResource::default();
+
+    assert_ne!(strategy.decide(&perception, &mut rng), Action::Work((6, 5)));
+    assert!(strategy.known().is_empty());
+}
+
+#[test]
+fn test_spiral_moves_outward_ring_by_ring() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let mut strategy = Spiral::default();
+    let mut distances = Vec::new();
+
+    // Le robot est téléporté sur chaque point de passage dès qu'il le vise.
+    let mut position = (15, 15);
+    for _ in 0..24 {
+        match strategy.decide(&perception_at(position, 31, 31), &mut rng) {
+            Action::MoveTo(waypoint) => {
+                distances.push(waypoint.0.abs_diff(15).max(waypoint.1.abs_diff(15)));
+                position = waypoint;
+            }
+            action => panic!("unexpected action {:?}", action),
+        }
+    }
+
+    assert!(distances[..8].iter().all(|&distance| distance == 3));
+    assert!(distances[8..].iter().all(|&distance| distance == 6));
+}
+
+#[test]
+fn test_frontier_returns_when_everything_is_seen() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let mut strategy = Frontier::default();
+    // Sur une carte 3x3, la case centrale voit toutes les autres sauf les coins.
+    let mut perception = perception_at((1, 1), 3, 3);
+    for position in [(0, 0), (2, 0), (0, 2), (2, 2)] {
+        perception.visible.push(VisibleTile {
+            position,
+            tile: TileType::Empty,
+            standable: true,
+        });
+    }
+
+    assert_eq!(strategy.decide(&perception, &mut rng), Action::ReturnToBase);
+    assert_eq!(strategy.seen().len(), 9);
+}